@@ -0,0 +1,142 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+mod solutions;
+
+use solutions::{find_solution, SOLUTIONS};
+
+const USAGE: &str = "Usage:
+    aoc run <day> <part> [--input PATH]
+    aoc list
+
+Without --input, puzzle input is read from day_XX/puzzle_input.txt relative to the current directory.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {:#}", e);
+        std::process::exit(1);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run {
+        day: u8,
+        part: u8,
+        input: Option<PathBuf>,
+    },
+    List,
+}
+
+fn run(args: &[String]) -> Result<()> {
+    match parse_args(args)? {
+        Command::List => {
+            for solution in SOLUTIONS {
+                println!("day {:02} part {}", solution.day, solution.part);
+            }
+        }
+        Command::Run { day, part, input } => {
+            let solution = find_solution(day, part)
+                .ok_or_else(|| anyhow!("No solution for day {} part {}", day, part))?;
+            let input_path = input.unwrap_or_else(|| {
+                PathBuf::from(format!("day_{:02}", day)).join(solution.default_input)
+            });
+            let input = std::fs::read_to_string(&input_path)
+                .with_context(|| format!("Failed to read {}", input_path.display()))?;
+
+            let start = Instant::now();
+            let answer = (solution.solve)(&input)?;
+            let elapsed = start.elapsed();
+
+            // The answer goes to stdout on its own so that it can be scripted
+            println!("{}", answer);
+            eprintln!("Day {} Part {} took {:?}", day, part, elapsed);
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_args(args: &[String]) -> Result<Command> {
+    match args.first().map(String::as_str) {
+        Some("list") => Ok(Command::List),
+        Some("run") => {
+            let mut positional = Vec::new();
+            let mut input = None;
+            let mut rest = args[1..].iter();
+            while let Some(arg) = rest.next() {
+                if arg == "--input" {
+                    let path = rest.next().context("--input requires a PATH")?;
+                    input = Some(PathBuf::from(path));
+                } else {
+                    positional.push(arg);
+                }
+            }
+
+            let [day, part] = positional[..] else {
+                bail!("run requires a day and a part\n\n{}", USAGE);
+            };
+            let day = day
+                .parse::<u8>()
+                .with_context(|| format!("Invalid day: {}", day))?;
+            let part = part
+                .parse::<u8>()
+                .with_context(|| format!("Invalid part: {}", part))?;
+
+            Ok(Command::Run { day, part, input })
+        }
+        _ => bail!("{}", USAGE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &str) -> Vec<String> {
+        input.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args_run() {
+        let expected = Command::Run {
+            day: 5,
+            part: 2,
+            input: None,
+        };
+        assert_eq!(parse_args(&args("run 5 2")).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_args_run_with_input() {
+        let expected = Command::Run {
+            day: 14,
+            part: 1,
+            input: Some(PathBuf::from("rocks.txt")),
+        };
+        assert_eq!(
+            parse_args(&args("run 14 1 --input rocks.txt")).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse_args(&args("run --input rocks.txt 14 1")).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_args_list() {
+        assert_eq!(parse_args(&args("list")).unwrap(), Command::List);
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("run 5")).is_err());
+        assert!(parse_args(&args("run five 1")).is_err());
+        assert!(parse_args(&args("run 5 1 --input")).is_err());
+    }
+}
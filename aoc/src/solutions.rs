@@ -0,0 +1,102 @@
+use anyhow::Result;
+
+pub struct Solution {
+    pub day: u8,
+    pub part: u8,
+    pub default_input: &'static str,
+    pub solve: fn(&str) -> Result<String>,
+}
+
+impl Solution {
+    const fn new(day: u8, part: u8, solve: fn(&str) -> Result<String>) -> Self {
+        Self {
+            day,
+            part,
+            default_input: "puzzle_input.txt",
+            solve,
+        }
+    }
+
+    const fn with_default_input(mut self, default_input: &'static str) -> Self {
+        self.default_input = default_input;
+        self
+    }
+}
+
+// Every solver is registered here, in day and part order.
+// Default inputs are relative to the day directory, matching where each binary expects them.
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::new(1, 1, |i| Ok(day_01_part_1::solve_part(i)?.to_string()))
+        .with_default_input("part_1/data/puzzle_input.txt"),
+    Solution::new(1, 2, |i| Ok(day_01_part_2::solve_part(i)?.to_string()))
+        .with_default_input("part_2/data/puzzle_input.txt"),
+    Solution::new(2, 1, |i| {
+        Ok(day_02_part_1::sum_of_possible_games(i, &day_02_part_1::Bag::standard())?.to_string())
+    }),
+    Solution::new(2, 2, |i| {
+        Ok(day_02_part_2::sum_of_game_powers(i)?.to_string())
+    }),
+    Solution::new(3, 1, |i| Ok(day_03_part_1::solve_part(i).to_string())),
+    Solution::new(3, 2, |i| Ok(day_03_part_2::solve_part(i).to_string())),
+    Solution::new(4, 1, |i| Ok(day_04_part_1::solve_part(i).to_string())),
+    Solution::new(4, 2, |i| Ok(day_04_part_2::solve_part(i).to_string())),
+    Solution::new(5, 1, |i| Ok(day_05_part_1::calculate(i)?.to_string())),
+    Solution::new(5, 2, |i| Ok(day_05_part_2::calculate(i)?.to_string())),
+    Solution::new(6, 1, |i| Ok(day_06_part_1::solve_part(i).to_string())),
+    Solution::new(7, 1, |i| Ok(day_07_part_1::solve_part(i).to_string())),
+    Solution::new(7, 2, |i| Ok(day_07_part_2::solve_part(i).to_string())),
+    Solution::new(8, 1, |i| Ok(day_08_part_1::solve_part(i).to_string())),
+    Solution::new(8, 2, |i| Ok(day_08_part_2::solve_part(i).to_string())),
+    Solution::new(9, 1, |i| Ok(day_09_part_1::solve_part(i).to_string())),
+    Solution::new(9, 2, |i| Ok(day_09_part_2::solve_part(i).to_string())),
+    Solution::new(10, 1, |i| Ok(day_10_part_1::solve_part(i).to_string())),
+    Solution::new(10, 2, |i| Ok(day_10_part_2::solve_part(i).to_string())),
+    Solution::new(11, 1, |i| Ok(day_11_part_1::solve_part(i).to_string())),
+    Solution::new(11, 2, |i| Ok(day_11_part_2::solve_part(i).to_string())),
+    Solution::new(13, 1, |i| {
+        Ok(day_13_part_1::calculate_pattern_summary(i)?.to_string())
+    }),
+    Solution::new(13, 2, |i| {
+        Ok(day_13_part_2::calculate_pattern_summary(i)?.to_string())
+    }),
+    Solution::new(14, 1, |i| Ok(day_14_part_1::solve_part(i).to_string())),
+    Solution::new(14, 2, |i| {
+        Ok(day_14_part_2::solve_part(i, 1000000000).to_string())
+    }),
+    Solution::new(15, 1, |i| Ok(day_15_part_1::solve_part(i).to_string())),
+    Solution::new(15, 2, |i| Ok(day_15_part_2::solve_part(i)?.to_string())),
+    Solution::new(16, 1, |i| Ok(day_16_part_1::solve_part(i).to_string())),
+    Solution::new(16, 2, |i| Ok(day_16_part_2::solve_part(i).to_string())),
+    Solution::new(17, 1, |i| Ok(day_17_part_1::solve_part(i).to_string())),
+    Solution::new(17, 2, |i| Ok(day_17_part_2::solve_part(i).to_string())),
+];
+
+pub fn find_solution(day: u8, part: u8) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.day == day && s.part == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions_are_unique_and_ordered() {
+        for pair in SOLUTIONS.windows(2) {
+            assert!((pair[0].day, pair[0].part) < (pair[1].day, pair[1].part));
+        }
+    }
+
+    #[test]
+    fn test_find_solution() {
+        assert!(find_solution(5, 2).is_some());
+        assert!(find_solution(6, 2).is_none());
+        assert!(find_solution(12, 1).is_none());
+    }
+
+    #[test]
+    fn test_solve_through_registry() {
+        let solution = find_solution(15, 1).unwrap();
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!((solution.solve)(input).unwrap(), "1320");
+    }
+}
@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    NoDigits,
+    InvalidUtf8,
+}
+
+// Line numbers start from one, like in an editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub line_number: usize,
+    pub line: String,
+    pub reason: Reason,
+}
+
+impl LineError {
+    pub fn new(line_number: usize, line: &str, reason: Reason) -> Self {
+        Self {
+            line_number,
+            line: line.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reason::NoDigits => write!(f, "no digits found"),
+            Reason::InvalidUtf8 => write!(f, "invalid UTF-8"),
+        }
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {} in {:?}",
+            self.line_number, self.reason, self.line
+        )
+    }
+}
+
+impl Error for LineError {}
+
+// Strict mode stops at the first bad line, lenient mode skips bad lines and reports them
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+pub fn skipped_lines_report(skipped: &[LineError]) -> String {
+    let mut report = format!("Skipped {} lines", skipped.len());
+    for error in skipped {
+        report.push_str(&format!("\n  {}", error));
+    }
+    report
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_skipped_lines_report() {
+        let skipped = vec![
+            LineError::new(2, "abc", Reason::NoDigits),
+            LineError::new(5, "", Reason::NoDigits),
+        ];
+
+        assert_eq!(
+            skipped_lines_report(&skipped),
+            "Skipped 2 lines\n  line 2: no digits found in \"abc\"\n  line 5: no digits found in \"\""
+        );
+    }
+}
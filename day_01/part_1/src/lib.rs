@@ -0,0 +1,57 @@
+mod diagnostics;
+mod options;
+mod stream;
+
+pub use diagnostics::{skipped_lines_report, LineError, Mode, Reason};
+pub use options::{Input, Options};
+pub use stream::{
+    sum_calibration_values, sum_calibration_values_with, ReadError, SkippedLines, StreamCalibration,
+};
+
+pub fn solve_part(input: &str) -> Result<u64, ReadError> {
+    Ok(sum_calibration_values::<u64, _>(input.as_bytes(), Mode::Strict)?.sum)
+}
+
+fn parse_line(line_number: usize, input_line: &str) -> Result<u8, LineError> {
+    let mut digits = input_line.chars().filter_map(|x| x.to_digit(10));
+    let first = digits
+        .next()
+        .ok_or_else(|| LineError::new(line_number, input_line, Reason::NoDigits))?;
+    let last = digits.next_back().unwrap_or(first);
+
+    Ok((first * 10 + last) as u8)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_line_returns_correct_value() {
+        assert_eq!(parse_line(1, "1abc2"), Ok(12));
+    }
+
+    #[test]
+    fn parse_line_without_digits_returns_error() {
+        assert_eq!(
+            parse_line(7, "treb_uchet").err(),
+            Some(LineError::new(7, "treb_uchet", Reason::NoDigits))
+        );
+    }
+
+    #[test]
+    fn solve_part_returns_correct_value() {
+        let input = std::fs::read_to_string("./data/test_input.txt").unwrap();
+
+        assert_eq!(solve_part(&input).unwrap(), 142);
+    }
+
+    #[test]
+    fn solve_part_reports_bad_line() {
+        assert_eq!(solve_part("1abc2\npqr3stu8vwx").unwrap(), 50);
+        assert_eq!(
+            solve_part("1abc2\nxyz").unwrap_err().to_string(),
+            "line 2: no digits found in \"xyz\""
+        );
+    }
+}
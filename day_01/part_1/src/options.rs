@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+use crate::Mode;
+
+const DEFAULT_INPUT: &str = "./data/puzzle_input.txt";
+
+#[derive(Debug, PartialEq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+// Command line options for the binary: `[--strict | --lenient] [--u128] [PATH | -]`
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub mode: Mode,
+    pub input: Input,
+    // Sums into a u128 instead of a u64
+    pub wide: bool,
+}
+
+impl Options {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+        let mut mode = Mode::default();
+        let mut input = None;
+        let mut wide = false;
+
+        for arg in args {
+            match arg.as_str() {
+                "--strict" => mode = Mode::Strict,
+                "--lenient" => mode = Mode::Lenient,
+                "--u128" => wide = true,
+                _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+                _ if input.is_some() => return Err(format!("Unexpected argument {}", arg)),
+                "-" => input = Some(Input::Stdin),
+                _ => input = Some(Input::File(PathBuf::from(arg))),
+            }
+        }
+
+        Ok(Options {
+            mode,
+            input: input.unwrap_or_else(|| Input::File(PathBuf::from(DEFAULT_INPUT))),
+            wide,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(
+            Options::from_args(args(&[])),
+            Ok(Options {
+                mode: Mode::Strict,
+                input: Input::File(PathBuf::from(DEFAULT_INPUT)),
+                wide: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_all_options() {
+        assert_eq!(
+            Options::from_args(args(&["--lenient", "--u128", "-"])),
+            Ok(Options {
+                mode: Mode::Lenient,
+                input: Input::Stdin,
+                wide: true,
+            })
+        );
+        assert_eq!(
+            Options::from_args(args(&["--lenient", "logs.txt", "--strict"])),
+            Ok(Options {
+                mode: Mode::Strict,
+                input: Input::File(PathBuf::from("logs.txt")),
+                wide: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert!(Options::from_args(args(&["--sloppy"])).is_err());
+        assert!(Options::from_args(args(&["a.txt", "b.txt"])).is_err());
+    }
+}
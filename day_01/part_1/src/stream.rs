@@ -0,0 +1,275 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::AddAssign;
+
+use crate::{parse_line, skipped_lines_report, LineError, Mode, Reason};
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Line(LineError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "Failed to read input: {}", e),
+            ReadError::Line(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Line(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(value: io::Error) -> Self {
+        ReadError::Io(value)
+    }
+}
+
+impl From<LineError> for ReadError {
+    fn from(value: LineError) -> Self {
+        ReadError::Line(value)
+    }
+}
+
+// Only the first few skipped lines are kept, cut short if they are long, and the rest are just
+// counted, so lenient mode uses bounded memory however many lines are bad
+#[derive(Debug, Default, PartialEq)]
+pub struct SkippedLines {
+    pub count: usize,
+    pub first: Vec<LineError>,
+}
+
+impl SkippedLines {
+    const KEPT: usize = 100;
+    const KEPT_CHARS: usize = 80;
+
+    fn push(&mut self, mut error: LineError) {
+        self.count += 1;
+        if self.first.len() < Self::KEPT {
+            if let Some((index, _)) = error.line.char_indices().nth(Self::KEPT_CHARS) {
+                error.line.truncate(index);
+                error.line.push_str("...");
+            }
+            self.first.push(error);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl fmt::Display for SkippedLines {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", skipped_lines_report(&self.first))?;
+        if self.count > self.first.len() {
+            write!(f, "\n  and {} more", self.count - self.first.len())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StreamCalibration<T> {
+    pub sum: T,
+    pub lines: usize,
+    pub skipped: SkippedLines,
+}
+
+// Reads one line at a time into the same buffer, so memory stays constant however long the
+// input is. The sum can be any type a two digit value fits in, u64 or u128 for inputs with
+// more lines than an i32 sum could cope with. Lines that aren't valid UTF-8 are bad lines like
+// any other, rather than a failure to read.
+pub fn sum_calibration_values<T, R>(
+    reader: R,
+    mode: Mode,
+) -> Result<StreamCalibration<T>, ReadError>
+where
+    T: Default + From<u8> + AddAssign,
+    R: BufRead,
+{
+    sum_calibration_values_with(reader, mode, parse_line)
+}
+
+// The same, reading each line's value with `parse` instead of taking its first and last digit
+pub fn sum_calibration_values_with<T, R, F>(
+    mut reader: R,
+    mode: Mode,
+    mut parse: F,
+) -> Result<StreamCalibration<T>, ReadError>
+where
+    T: Default + From<u8> + AddAssign,
+    R: BufRead,
+    F: FnMut(usize, &str) -> Result<u8, LineError>,
+{
+    let mut calibration = StreamCalibration {
+        sum: T::default(),
+        lines: 0,
+        skipped: SkippedLines::default(),
+    };
+    let mut line = Vec::new();
+
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        calibration.lines += 1;
+
+        let ending = line.iter().rev().take_while(|b| matches!(b, b'\n' | b'\r'));
+        let bytes = &line[..line.len() - ending.count()];
+        let parsed = match std::str::from_utf8(bytes) {
+            Ok(line) => parse(calibration.lines, line),
+            Err(_) => Err(LineError::new(
+                calibration.lines,
+                &String::from_utf8_lossy(bytes),
+                Reason::InvalidUtf8,
+            )),
+        };
+        match parsed {
+            Ok(value) => calibration.sum += T::from(value),
+            Err(error) if mode == Mode::Lenient => calibration.skipped.push(error),
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    Ok(calibration)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{BufReader, Cursor, Read};
+
+    #[test]
+    fn test_sum_calibration_values() {
+        let file = std::fs::File::open("./data/test_input.txt").unwrap();
+        let calibration: StreamCalibration<u64> =
+            sum_calibration_values(BufReader::new(file), Mode::Strict).unwrap();
+
+        assert_eq!(calibration.sum, 142);
+        assert_eq!(calibration.lines, 4);
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        let input = Cursor::new("1abc2\r\npqr3stu8vwx\r\n");
+        let calibration: StreamCalibration<u128> =
+            sum_calibration_values(input, Mode::Strict).unwrap();
+
+        assert_eq!(calibration.sum, 50);
+    }
+
+    #[test]
+    fn test_lenient_mode_skips_bad_lines() {
+        let input = Cursor::new("1abc2\nnope\n\ntreb7uchet");
+        let calibration: StreamCalibration<u64> =
+            sum_calibration_values(input, Mode::Lenient).unwrap();
+
+        assert_eq!(calibration.sum, 89);
+        assert_eq!(calibration.lines, 4);
+        assert_eq!(
+            calibration.skipped,
+            SkippedLines {
+                count: 2,
+                first: vec![
+                    LineError::new(2, "nope", Reason::NoDigits),
+                    LineError::new(3, "", Reason::NoDigits),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_strict_mode_fails_on_bad_line() {
+        let input = Cursor::new("1abc2\nnope\n");
+        let error = sum_calibration_values::<u64, _>(input, Mode::Strict).unwrap_err();
+
+        assert!(matches!(
+            error,
+            ReadError::Line(LineError { line_number: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_a_bad_line() {
+        let input = [b'1', 0xff, b'2', b'\n', b'3', b'\n'];
+        let calibration =
+            sum_calibration_values::<u64, _>(Cursor::new(input), Mode::Lenient).unwrap();
+
+        assert_eq!(calibration.sum, 33);
+        assert_eq!(
+            calibration.skipped.first,
+            vec![LineError::new(1, "1\u{fffd}2", Reason::InvalidUtf8)]
+        );
+
+        let error = sum_calibration_values::<u64, _>(Cursor::new(input), Mode::Strict).unwrap_err();
+        assert_eq!(error.to_string(), "line 1: invalid UTF-8 in \"1\u{fffd}2\"");
+    }
+
+    #[test]
+    fn test_skipped_lines_are_bounded() {
+        let input = format!("{}\n", "x".repeat(1000)).repeat(250);
+        let kept = format!("{}...", "x".repeat(80));
+        let calibration =
+            sum_calibration_values::<u64, _>(input.as_bytes(), Mode::Lenient).unwrap();
+
+        assert_eq!(calibration.skipped.count, 250);
+        assert_eq!(calibration.skipped.first.len(), 100);
+        assert_eq!(calibration.skipped.first[0].line, kept);
+        assert!(calibration.skipped.to_string().ends_with(&format!(
+            "\n  line 100: no digits found in {:?}\n  and 150 more",
+            kept
+        )));
+    }
+
+    // Produces the same line over and over without ever holding more than one copy of it
+    struct RepeatedLines {
+        line: &'static [u8],
+        remaining: usize,
+        position: usize,
+    }
+
+    impl Read for RepeatedLines {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let mut written = 0;
+            while written < buf.len() && self.remaining > 0 {
+                let count = (self.line.len() - self.position).min(buf.len() - written);
+                buf[written..written + count]
+                    .copy_from_slice(&self.line[self.position..self.position + count]);
+                written += count;
+                self.position += count;
+                if self.position == self.line.len() {
+                    self.position = 0;
+                    self.remaining -= 1;
+                }
+            }
+            Ok(written)
+        }
+    }
+
+    #[test]
+    fn test_large_input() {
+        let lines = 200_000;
+        let input = BufReader::new(RepeatedLines {
+            line: b"xx9yyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy8zz\n",
+            remaining: lines,
+            position: 0,
+        });
+        let calibration: StreamCalibration<u128> =
+            sum_calibration_values(input, Mode::Strict).unwrap();
+
+        assert_eq!(calibration.sum, 98 * lines as u128);
+        assert_eq!(calibration.lines, lines);
+    }
+}
@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use day_01_part_2::{solve_part, solve_part_with_find};
+
+// Repeats awkward lines, with overlapping words and digits buried in noise, to a few megabytes
+fn generate_input(bytes: usize) -> String {
+    let lines = [
+        "two1nine",
+        "eightwothree",
+        "abcone2threexyz",
+        "xtwone3four",
+        "4nineeightseven2",
+        "zoneight234",
+        "7pqrstsixteen",
+        "qwertyuiopasdfghjklzxcvbnmoneightqwertyuiopasdfghjklzxcvbnm",
+        "9sevenineeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+    ];
+
+    let mut input = String::with_capacity(bytes);
+    for line in lines.iter().cycle() {
+        if input.len() >= bytes {
+            break;
+        }
+        input.push_str(line);
+        input.push('\n');
+    }
+    input
+}
+
+fn calibration(c: &mut Criterion) {
+    let input = generate_input(4 * 1024 * 1024);
+
+    let mut group = c.benchmark_group("calibration");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("aho_corasick", |b| {
+        b.iter(|| solve_part(black_box(&input)).unwrap())
+    });
+    group.bench_function("find", |b| {
+        b.iter(|| solve_part_with_find(black_box(&input)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, calibration);
+criterion_main!(benches);
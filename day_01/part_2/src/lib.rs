@@ -0,0 +1,245 @@
+use std::io::BufRead;
+use std::ops::AddAssign;
+
+use day_01_part_1::sum_calibration_values_with;
+use lazy_static::lazy_static;
+
+mod matcher;
+mod options;
+mod vocabulary;
+
+pub use matcher::DigitMatcher;
+pub use options::Options;
+pub use vocabulary::Vocabulary;
+
+pub use day_01_part_1::{
+    skipped_lines_report, LineError, Mode, ReadError, Reason, SkippedLines, StreamCalibration,
+};
+
+lazy_static! {
+    static ref MATCHER: DigitMatcher = DigitMatcher::default();
+}
+
+pub fn solve_part(input: &str) -> Result<u64, ReadError> {
+    Ok(calibrate::<u64, _>(input.as_bytes(), Mode::Strict)?.sum)
+}
+
+// Streams the input a line at a time like part 1, with digit words counting as digits
+pub fn calibrate<T, R>(reader: R, mode: Mode) -> Result<StreamCalibration<T>, ReadError>
+where
+    T: Default + From<u8> + AddAssign,
+    R: BufRead,
+{
+    sum_calibration_values_with(reader, mode, parse_line)
+}
+
+pub fn calibrate_with_vocabulary<T, R>(
+    reader: R,
+    vocabulary: &Vocabulary,
+    mode: Mode,
+) -> Result<StreamCalibration<T>, ReadError>
+where
+    T: Default + From<u8> + AddAssign,
+    R: BufRead,
+{
+    let matcher = DigitMatcher::new(vocabulary);
+    sum_calibration_values_with(reader, mode, |line_number, line| {
+        parse_line_with_matcher(&matcher, line_number, line)
+    })
+}
+
+fn parse_line(line_number: usize, input_line: &str) -> Result<u8, LineError> {
+    parse_line_with_matcher(&MATCHER, line_number, input_line)
+}
+
+// Vocabularies only map words to single digits, so the value always fits
+fn parse_line_with_matcher(
+    matcher: &DigitMatcher,
+    line_number: usize,
+    input_line: &str,
+) -> Result<u8, LineError> {
+    matcher
+        .calibration_value(input_line)
+        .map(|value| value as u8)
+        .ok_or_else(|| LineError::new(line_number, input_line, Reason::NoDigits))
+}
+
+// The original approach of searching for every pattern from both ends of the line, kept to
+// benchmark the matcher against and hidden from the docs. None if any line has no digits.
+#[doc(hidden)]
+pub fn solve_part_with_find(input: &str) -> Option<u32> {
+    input.lines().map(parse_line_with_find).sum()
+}
+
+fn parse_line_with_find(input_line: &str) -> Option<u32> {
+    let patterns = vec![
+        ("one", "1"),
+        ("two", "2"),
+        ("three", "3"),
+        ("four", "4"),
+        ("five", "5"),
+        ("six", "6"),
+        ("seven", "7"),
+        ("eight", "8"),
+        ("nine", "9"),
+        ("1", "1"),
+        ("2", "2"),
+        ("3", "3"),
+        ("4", "4"),
+        ("5", "5"),
+        ("6", "6"),
+        ("7", "7"),
+        ("8", "8"),
+        ("9", "9"),
+    ];
+
+    let first_match = patterns
+        .iter()
+        .filter_map(|(pattern, value)| input_line.find(pattern).map(|index| (index, value)))
+        .min_by_key(|&(index, _)| index)?
+        .1;
+
+    let last_match = patterns
+        .iter()
+        .filter_map(|(pattern, value)| input_line.rfind(pattern).map(|index| (index, value)))
+        .max_by_key(|&(index, _)| index)?
+        .1;
+
+    let combined = format!("{}{}", first_match, last_match);
+
+    combined.parse::<u32>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_returns_correct_mixed_words_numbers() {
+        let test_data = "onetwo3";
+        let expected = 13;
+        let actual = parse_line(1, test_data).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_line_returns_correct_overlapping_words() {
+        let test_data = "onetwone";
+        let expected = 11;
+        let actual = parse_line(1, test_data).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_line_returns_correct_overlapping_words_with_numbers() {
+        let test_data = "onetwone3";
+        let expected = 13;
+        let actual = parse_line(1, test_data).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_line_returns_correct_for_test_values() {
+        let test_data = vec![
+            ("two1nine", 29),
+            ("eightwothree", 83),
+            ("abcone2threexyz", 13),
+            ("xtwone3four", 24),
+            ("4nineeightseven2", 42),
+            ("zoneight234", 14),
+            ("7pqrstsixteen", 76),
+        ];
+
+        for (input, expected) in test_data {
+            let actual = parse_line(1, input).unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_matcher_agrees_with_find() {
+        let input = std::fs::read_to_string("./data/test_input.txt").unwrap();
+
+        for line in input
+            .lines()
+            .chain(["eightwo", "1", "oneightwone", "9sevenine", "abc"])
+        {
+            assert_eq!(
+                parse_line(1, line).ok().map(u32::from),
+                parse_line_with_find(line),
+                "{}",
+                line
+            );
+        }
+        assert_eq!(solve_part(&input).unwrap(), 281);
+        assert_eq!(solve_part_with_find(&input), Some(281));
+        assert_eq!(solve_part_with_find("1abc2\nabc"), None);
+    }
+
+    #[test]
+    fn test_parse_line_without_digits_returns_error() {
+        assert_eq!(
+            parse_line(3, "abcxyz"),
+            Err(LineError::new(3, "abcxyz", Reason::NoDigits))
+        );
+    }
+
+    const MESSY_INPUT: &str = "two1nine\nnothing to see\nxtwone3four\n\n7pqrstsixteen";
+
+    #[test]
+    fn test_strict_mode_fails_on_first_bad_line() {
+        let error = calibrate::<u64, _>(MESSY_INPUT.as_bytes(), Mode::Strict).unwrap_err();
+
+        assert!(matches!(
+            error,
+            ReadError::Line(LineError { line_number: 2, .. })
+        ));
+        assert!(solve_part(MESSY_INPUT).is_err());
+    }
+
+    #[test]
+    fn test_lenient_mode_skips_bad_lines() {
+        let calibration = calibrate::<u64, _>(MESSY_INPUT.as_bytes(), Mode::Lenient).unwrap();
+
+        assert_eq!(
+            calibration,
+            StreamCalibration {
+                sum: 29 + 24 + 76,
+                lines: 5,
+                skipped: SkippedLines {
+                    count: 2,
+                    first: vec![
+                        LineError::new(2, "nothing to see", Reason::NoDigits),
+                        LineError::new(4, "", Reason::NoDigits),
+                    ],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_skipped_lines_are_bounded() {
+        let input = "nothing to see\n".repeat(250);
+        let calibration = calibrate::<u64, _>(input.as_bytes(), Mode::Lenient).unwrap();
+
+        assert_eq!(calibration.skipped.count, 250);
+        assert_eq!(calibration.skipped.first.len(), 100);
+    }
+
+    #[test]
+    fn test_calibrate_with_vocabulary() {
+        let vocabulary = Vocabulary::from_file("./data/vocabularies/french.toml").unwrap();
+        let calibration = calibrate_with_vocabulary::<u64, _>(
+            "deux1neuf\none\nhuit".as_bytes(),
+            &vocabulary,
+            Mode::Lenient,
+        )
+        .unwrap();
+
+        assert_eq!(calibration.sum, 29 + 88);
+        assert_eq!(
+            calibration.skipped.first,
+            vec![LineError::new(2, "one", Reason::NoDigits)]
+        );
+    }
+}
@@ -1,94 +1,36 @@
-fn main() {
-    let input = std::fs::read_to_string("./data/puzzle_input.txt").unwrap();
-    let result: u32 = input.lines().map(parse_line).sum();
-
-    println!("Result: {}", result);
-}
-
-fn parse_line(input_line: &str) -> u32 {
-    let patterns = vec![
-        ("one", "1"),
-        ("two", "2"),
-        ("three", "3"),
-        ("four", "4"),
-        ("five", "5"),
-        ("six", "6"),
-        ("seven", "7"),
-        ("eight", "8"),
-        ("nine", "9"),
-        ("1", "1"),
-        ("2", "2"),
-        ("3", "3"),
-        ("4", "4"),
-        ("5", "5"),
-        ("6", "6"),
-        ("7", "7"),
-        ("8", "8"),
-        ("9", "9"),
-    ];
-
-    let first_match = patterns
-        .iter()
-        .filter_map(|(pattern, value)| input_line.find(pattern).map(|index| (index, value)))
-        .min_by_key(|&(index, _)| index)
-        .unwrap()
-        .1;
-
-    let last_match = patterns
-        .iter()
-        .filter_map(|(pattern, value)| input_line.rfind(pattern).map(|index| (index, value)))
-        .max_by_key(|&(index, _)| index)
-        .unwrap()
-        .1;
-
-    let combined = format!("{}{}", first_match, last_match);
-
-    combined.parse::<u32>().unwrap()
-}
+use std::fs::File;
+use std::io::BufReader;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+use day_01_part_2::{calibrate, calibrate_with_vocabulary, Options, Vocabulary};
 
-    #[test]
-    fn test_parse_line_returns_correct_mixed_words_numbers() {
-        let test_data = "onetwo3";
-        let expected = 13;
-        let actual = parse_line(test_data);
-        assert_eq!(expected, actual);
-    }
-
-    #[test]
-    fn test_parse_line_returns_correct_overlapping_words() {
-        let test_data = "onetwone";
-        let expected = 11;
-        let actual = parse_line(test_data);
-        assert_eq!(expected, actual);
-    }
-
-    #[test]
-    fn test_parse_line_returns_correct_overlapping_words_with_numbers() {
-        let test_data = "onetwone3";
-        let expected = 13;
-        let actual = parse_line(test_data);
-        assert_eq!(expected, actual);
+fn main() {
+    let options = Options::from_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+
+    let path = "./data/puzzle_input.txt";
+    let reader = BufReader::new(File::open(path).unwrap_or_else(|e| {
+        eprintln!("Failed to open {}: {}", path, e);
+        std::process::exit(1);
+    }));
+    let calibration = match &options.vocabulary {
+        Some(path) => {
+            let vocabulary = Vocabulary::from_file(path).unwrap_or_else(|e| {
+                eprintln!("Error: {:#}", e);
+                std::process::exit(1);
+            });
+            calibrate_with_vocabulary::<u64, _>(reader, &vocabulary, options.mode)
+        }
+        None => calibrate::<u64, _>(reader, options.mode),
     }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
 
-    #[test]
-    fn test_parse_line_returns_correct_for_test_values() {
-        let test_data = vec![
-            ("two1nine", 29),
-            ("eightwothree", 83),
-            ("abcone2threexyz", 13),
-            ("xtwone3four", 24),
-            ("4nineeightseven2", 42),
-            ("zoneight234", 14),
-            ("7pqrstsixteen", 76),
-        ];
-
-        for (input, expected) in test_data {
-            let actual = parse_line(input);
-            assert_eq!(expected, actual);
-        }
+    if !calibration.skipped.is_empty() {
+        eprintln!("{}", calibration.skipped);
     }
+    println!("Result: {}", calibration.sum);
 }
@@ -0,0 +1,194 @@
+use std::borrow::Cow;
+
+use aho_corasick::{AhoCorasick, MatchKind};
+
+use crate::vocabulary::Vocabulary;
+
+// Finds the first digit with one automaton scanning forwards and the last with a second
+// automaton over the reversed patterns scanning the reversed line. Overlapping words like
+// `eightwo` work because each scan only ever needs the match nearest its own end.
+pub struct DigitMatcher {
+    forwards: AhoCorasick,
+    backwards: AhoCorasick,
+    // The first and last digit each pattern stands for
+    digits: Vec<(u32, u32)>,
+    case_sensitive: bool,
+}
+
+impl DigitMatcher {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let patterns = vocabulary.patterns();
+        // Where words start at the same place, like `six` and `sixteen`, the longest wins
+        let build = |patterns: Vec<Vec<u8>>| {
+            AhoCorasick::builder()
+                .match_kind(MatchKind::LeftmostLongest)
+                .build(patterns)
+                .expect("Digit patterns are valid")
+        };
+
+        Self {
+            forwards: build(
+                patterns
+                    .iter()
+                    .map(|(pattern, _, _)| pattern.bytes().collect())
+                    .collect(),
+            ),
+            backwards: build(
+                patterns
+                    .iter()
+                    .map(|(pattern, _, _)| pattern.bytes().rev().collect())
+                    .collect(),
+            ),
+            digits: patterns
+                .iter()
+                .map(|(_, first, last)| (*first, *last))
+                .collect(),
+            case_sensitive: vocabulary.case_sensitive,
+        }
+    }
+
+    pub fn first(&self, line: &str) -> Option<u32> {
+        self.forwards
+            .find(self.normalise(line).as_ref())
+            .map(|found| self.digits[found.pattern()].0)
+    }
+
+    pub fn last(&self, line: &str) -> Option<u32> {
+        let reversed = self.normalise(line).bytes().rev().collect::<Vec<_>>();
+        self.backwards
+            .find(&reversed)
+            .map(|found| self.digits[found.pattern()].1)
+    }
+
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        Some(self.first(line)? * 10 + self.last(line)?)
+    }
+
+    fn normalise<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        if self.case_sensitive {
+            Cow::Borrowed(line)
+        } else {
+            Cow::Owned(line.to_lowercase())
+        }
+    }
+}
+
+impl Default for DigitMatcher {
+    fn default() -> Self {
+        Self::new(&Vocabulary::english())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_first_and_last() {
+        let matcher = DigitMatcher::default();
+
+        assert_eq!(matcher.first("xtwone3four"), Some(2));
+        assert_eq!(matcher.last("xtwone3four"), Some(4));
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let matcher = DigitMatcher::default();
+
+        assert_eq!(matcher.calibration_value("eightwo"), Some(82));
+        assert_eq!(matcher.calibration_value("oneight"), Some(18));
+        assert_eq!(matcher.calibration_value("sevenine"), Some(79));
+    }
+
+    #[test]
+    fn test_single_digit() {
+        let matcher = DigitMatcher::default();
+
+        assert_eq!(matcher.calibration_value("abc7def"), Some(77));
+        assert_eq!(matcher.calibration_value("nine"), Some(99));
+    }
+
+    #[test]
+    fn test_english_is_case_sensitive() {
+        let matcher = DigitMatcher::default();
+
+        assert_eq!(matcher.calibration_value("ONE2three"), Some(23));
+    }
+
+    fn matcher_for(language: &str) -> DigitMatcher {
+        let path = format!("./data/vocabularies/{}.toml", language);
+        DigitMatcher::new(&Vocabulary::from_file(path).unwrap())
+    }
+
+    #[test]
+    fn test_french() {
+        let matcher = matcher_for("french");
+
+        assert_eq!(matcher.calibration_value("abcdeuxyz7"), Some(27));
+        // cinq and quatre share their q
+        assert_eq!(matcher.calibration_value("cinquatre"), Some(54));
+        assert_eq!(matcher.calibration_value("HUITxxSEPT"), Some(87));
+        // Neither one nor two count in French
+        assert_eq!(matcher.calibration_value("one8two"), Some(88));
+    }
+
+    #[test]
+    fn test_german() {
+        let matcher = matcher_for("german");
+
+        // drei and eins share their ei
+        assert_eq!(matcher.calibration_value("dreins"), Some(31));
+        assert_eq!(matcher.calibration_value("Fünfundzwanzig"), Some(55));
+        assert_eq!(matcher.calibration_value("ZWÖLFachtzehn"), Some(10));
+    }
+
+    #[test]
+    fn test_spanish() {
+        let matcher = matcher_for("spanish");
+
+        // uno and once share their o
+        assert_eq!(matcher.calibration_value("unonce"), Some(11));
+        assert_eq!(matcher.calibration_value("docenueve"), Some(19));
+        assert_eq!(matcher.calibration_value("seisiete"), Some(67));
+    }
+
+    #[test]
+    fn test_mixed_languages() {
+        let vocabulary: Vocabulary = indoc! {"
+            case_sensitive = false
+
+            [words]
+            one = 1
+            two = 2
+            huit = 8
+            neuf = 9
+            eins = 1
+        "}
+        .parse()
+        .unwrap();
+        let matcher = DigitMatcher::new(&vocabulary);
+
+        // huit and two share a t, one and neuf share an ne
+        assert_eq!(matcher.calibration_value("huitwo"), Some(82));
+        assert_eq!(matcher.calibration_value("oneuf"), Some(19));
+        assert_eq!(matcher.calibration_value("xxTWOeins"), Some(21));
+    }
+
+    #[test]
+    fn test_longest_word_wins() {
+        let vocabulary: Vocabulary = "words = { six = 6, sixteen = 16 }".parse().unwrap();
+        let matcher = DigitMatcher::new(&vocabulary);
+
+        assert_eq!(matcher.calibration_value("sixteen"), Some(16));
+        assert_eq!(matcher.calibration_value("sixtee"), Some(66));
+    }
+
+    #[test]
+    fn test_no_digits() {
+        let matcher = DigitMatcher::default();
+
+        assert_eq!(matcher.calibration_value("abcdef"), None);
+        assert_eq!(matcher.calibration_value(""), None);
+    }
+}
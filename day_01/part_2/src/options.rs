@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use crate::Mode;
+
+// Command line options for the binary: `[--strict | --lenient] [--vocabulary PATH]`
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub mode: Mode,
+    // A vocabulary file to read the digit words from instead of English
+    pub vocabulary: Option<PathBuf>,
+}
+
+impl Options {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+        let mut mode = Mode::default();
+        let mut vocabulary = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--strict" => mode = Mode::Strict,
+                "--lenient" => mode = Mode::Lenient,
+                "--vocabulary" => match args.next() {
+                    Some(path) => vocabulary = Some(PathBuf::from(path)),
+                    None => return Err("--vocabulary needs a file path".to_string()),
+                },
+                _ => {
+                    return Err(format!(
+                        "Unknown argument {}, expected --strict, --lenient or --vocabulary PATH",
+                        arg
+                    ))
+                }
+            }
+        }
+
+        Ok(Options { mode, vocabulary })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(
+            Options::from_args(args(&[])),
+            Ok(Options {
+                mode: Mode::Strict,
+                vocabulary: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_all_options() {
+        assert_eq!(
+            Options::from_args(args(&[
+                "--lenient",
+                "--vocabulary",
+                "data/vocabularies/french.toml"
+            ])),
+            Ok(Options {
+                mode: Mode::Lenient,
+                vocabulary: Some(PathBuf::from("data/vocabularies/french.toml")),
+            })
+        );
+        assert_eq!(
+            Options::from_args(args(&["--lenient", "--strict"])),
+            Ok(Options {
+                mode: Mode::Strict,
+                vocabulary: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert!(Options::from_args(args(&["--sloppy"])).is_err());
+        assert!(Options::from_args(args(&["--vocabulary"])).is_err());
+    }
+}
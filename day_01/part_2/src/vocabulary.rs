@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+const ENGLISH: &str = include_str!("../data/vocabularies/english.toml");
+
+// The words that count as digits alongside the numerals 1 to 9. A word worth more than
+// one digit, like `dix`, gives its leading digit when it is the first match in a line and
+// its trailing digit when it is the last.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Vocabulary {
+    #[serde(default = "case_sensitive_by_default")]
+    pub case_sensitive: bool,
+    pub words: BTreeMap<String, u32>,
+}
+
+fn case_sensitive_by_default() -> bool {
+    true
+}
+
+impl Vocabulary {
+    pub fn english() -> Self {
+        ENGLISH.parse().expect("Built in vocabulary is valid")
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read vocabulary {}", path.display()))?
+            .parse()
+            .with_context(|| format!("Failed to load vocabulary {}", path.display()))
+    }
+
+    // Every pattern to match with the first and last digit it stands for
+    pub(crate) fn patterns(&self) -> Vec<(String, u32, u32)> {
+        let words = self.words.iter().map(|(word, value)| {
+            let word = if self.case_sensitive {
+                word.clone()
+            } else {
+                word.to_lowercase()
+            };
+            (word, leading_digit(*value), value % 10)
+        });
+        let numerals = (1..=9).map(|digit| (digit.to_string(), digit, digit));
+
+        words.chain(numerals).collect()
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let vocabulary: Vocabulary = toml::from_str(s).context("Invalid vocabulary")?;
+
+        for word in vocabulary.words.keys() {
+            if word.is_empty() {
+                bail!("Vocabulary contains an empty word");
+            }
+            if word.chars().any(|c| c.is_ascii_digit()) {
+                bail!("Word {} contains a numeral", word);
+            }
+        }
+
+        Ok(vocabulary)
+    }
+}
+
+fn leading_digit(mut value: u32) -> u32 {
+    while value >= 10 {
+        value /= 10;
+    }
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_english() {
+        let vocabulary = Vocabulary::english();
+
+        assert!(vocabulary.case_sensitive);
+        assert_eq!(vocabulary.words.len(), 9);
+        assert_eq!(vocabulary.words["seven"], 7);
+    }
+
+    #[test]
+    fn test_from_file() {
+        let vocabulary = Vocabulary::from_file("./data/vocabularies/german.toml").unwrap();
+
+        assert!(!vocabulary.case_sensitive);
+        assert_eq!(vocabulary.words["fünf"], 5);
+        assert_eq!(vocabulary.words["zwölf"], 12);
+    }
+
+    #[test]
+    fn test_from_file_missing() {
+        assert!(Vocabulary::from_file("./data/vocabularies/klingon.toml").is_err());
+    }
+
+    #[test]
+    fn test_case_sensitive_by_default() {
+        let vocabulary: Vocabulary = "words = { uno = 1 }".parse().unwrap();
+
+        assert!(vocabulary.case_sensitive);
+    }
+
+    #[test]
+    fn test_invalid_vocabularies() {
+        assert!("case_sensitive = false".parse::<Vocabulary>().is_err());
+        assert!("words = { \"\" = 1 }".parse::<Vocabulary>().is_err());
+        assert!("words = { one1 = 1 }".parse::<Vocabulary>().is_err());
+        assert!("words = { one = -1 }".parse::<Vocabulary>().is_err());
+    }
+
+    #[test]
+    fn test_patterns() {
+        let vocabulary: Vocabulary = indoc! {"
+            case_sensitive = false
+
+            [words]
+            Dix = 10
+            huit = 8
+        "}
+        .parse()
+        .unwrap();
+        let patterns = vocabulary.patterns();
+
+        assert_eq!(patterns.len(), 11);
+        assert!(patterns.contains(&("dix".to_string(), 1, 0)));
+        assert!(patterns.contains(&("huit".to_string(), 8, 8)));
+        assert!(patterns.contains(&("3".to_string(), 3, 3)));
+    }
+}
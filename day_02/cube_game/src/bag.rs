@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+// The colours the puzzle's bag holds, in the order the puzzle lists them
+pub const STANDARD_COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+// How many cubes of each colour are in the bag, any colour not listed has none
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Bag(HashMap<String, u32>);
+
+impl Bag {
+    pub fn new(counts: HashMap<String, u32>) -> Self {
+        Self(counts)
+    }
+
+    // The bag from the puzzle
+    pub fn standard() -> Self {
+        Self::from([("red", 12), ("green", 13), ("blue", 14)])
+    }
+
+    pub fn count(&self, colour: &str) -> u32 {
+        self.0.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    // The counts of the given colours multiplied together, so a missing colour gives zero. Three
+    // colours always fit in a u128, any more saturate rather than overflow.
+    pub fn power(&self, colours: &[&str]) -> u128 {
+        colours
+            .iter()
+            .map(|colour| u128::from(self.count(colour)))
+            .fold(1, u128::saturating_mul)
+    }
+
+    pub(crate) fn raise(&mut self, colour: &str, count: u32) {
+        let current = self.0.entry(colour.to_string()).or_insert(0);
+        *current = (*current).max(count);
+    }
+}
+
+impl<const N: usize> From<[(&str, u32); N]> for Bag {
+    fn from(counts: [(&str, u32); N]) -> Self {
+        Self(
+            counts
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        let bag = Bag::standard();
+
+        assert_eq!(bag.count("green"), 13);
+        assert_eq!(bag.count("purple"), 0);
+    }
+
+    #[test]
+    fn test_power() {
+        let bag = Bag::from([("red", 4), ("green", 2), ("blue", 6)]);
+
+        assert_eq!(bag.power(&STANDARD_COLOURS), 48);
+        assert_eq!(bag.power(&["red", "purple"]), 0);
+
+        let large = Bag::from([("red", u32::MAX), ("green", u32::MAX), ("blue", u32::MAX)]);
+        assert_eq!(large.power(&STANDARD_COLOURS), u128::from(u32::MAX).pow(3));
+        assert_eq!(large.power(&["red"; 5]), u128::MAX);
+    }
+
+    #[test]
+    fn test_raise() {
+        let mut bag = Bag::from([("red", 4)]);
+        bag.raise("red", 2);
+        bag.raise("red", 7);
+        bag.raise("blue", 1);
+
+        assert_eq!(bag, Bag::from([("red", 7), ("blue", 1)]));
+    }
+}
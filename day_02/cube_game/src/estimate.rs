@@ -0,0 +1,505 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::bag::Bag;
+use crate::game::Game;
+
+// Belief about how many cubes of one colour the bag holds before looking at any draws
+#[derive(Debug, Clone, PartialEq)]
+pub enum Prior {
+    // Every count from zero up to and including max is equally likely
+    Uniform { max: u32 },
+    // The weight of each count by index, they don't need to add up to one but must be finite and
+    // not negative
+    Weights(Vec<f64>),
+}
+
+impl Prior {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Prior::Uniform { .. } => true,
+            Prior::Weights(weights) => weights.iter().all(|w| w.is_finite() && *w >= 0.0),
+        }
+    }
+
+    fn max(&self) -> usize {
+        match self {
+            Prior::Uniform { max } => *max as usize,
+            Prior::Weights(weights) => weights.len().saturating_sub(1),
+        }
+    }
+
+    fn weight(&self, count: u32) -> f64 {
+        match self {
+            Prior::Uniform { max } if count <= *max => 1.0,
+            Prior::Uniform { .. } => 0.0,
+            Prior::Weights(weights) => weights.get(count as usize).copied().unwrap_or(0.0),
+        }
+    }
+}
+
+// The most cubes the priors can allow between every colour, which bounds the tables the search
+// keeps. The search itself grows with the product of the maxima, so priors that come near this
+// are slow long before they are rejected.
+pub const MAX_CUBES: usize = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EstimateError {
+    // A prior has a negative or non-finite weight
+    InvalidWeights(String),
+    // The priors up to and including this colour allow more than MAX_CUBES cubes
+    TooManyCubes(String),
+    // No bag the priors allow could have produced the draws
+    Impossible,
+}
+
+impl fmt::Display for EstimateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EstimateError::InvalidWeights(colour) => {
+                write!(
+                    f,
+                    "the prior for {} has a negative or non-finite weight",
+                    colour
+                )
+            }
+            EstimateError::TooManyCubes(colour) => write!(
+                f,
+                "the priors allow more than {} cubes by {}",
+                MAX_CUBES, colour
+            ),
+            EstimateError::Impossible => write!(f, "no bag the priors allow fits the draws"),
+        }
+    }
+}
+
+impl Error for EstimateError {}
+
+// Models each draw as a handful taken from the bag without replacement, with the cubes put
+// back before the next draw. Every colour that is drawn or has a prior is part of the bag,
+// and the rest of the handful is assumed to be only the colours that were shown.
+#[derive(Debug, Clone)]
+pub struct Estimator {
+    pub default_prior: Prior,
+    pub priors: HashMap<String, Prior>,
+}
+
+impl Default for Estimator {
+    fn default() -> Self {
+        Self {
+            default_prior: Prior::Uniform { max: 20 },
+            priors: HashMap::new(),
+        }
+    }
+}
+
+// How likely each count of one colour is, by index
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution(Vec<f64>);
+
+impl Distribution {
+    pub fn probability(&self, count: u32) -> f64 {
+        self.0.get(count as usize).copied().unwrap_or(0.0)
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(count, p)| count as f64 * p)
+            .sum()
+    }
+
+    // Ties go to the smaller count
+    pub fn mode(&self) -> u32 {
+        self.0
+            .iter()
+            .enumerate()
+            .fold(
+                (0, f64::MIN),
+                |best, (count, &p)| {
+                    if p > best.1 {
+                        (count, p)
+                    } else {
+                        best
+                    }
+                },
+            )
+            .0 as u32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Posterior {
+    marginals: HashMap<String, Distribution>,
+    most_likely: Bag,
+}
+
+impl Posterior {
+    pub fn colour(&self, colour: &str) -> Option<&Distribution> {
+        self.marginals.get(colour)
+    }
+
+    // The single bag with the highest posterior probability, which can differ from the modes
+    // of the marginals taken one colour at a time
+    pub fn most_likely_bag(&self) -> &Bag {
+        &self.most_likely
+    }
+}
+
+impl Estimator {
+    pub fn prior(&self, colour: &str) -> &Prior {
+        self.priors.get(colour).unwrap_or(&self.default_prior)
+    }
+
+    pub fn posterior(&self, game: &Game) -> Result<Posterior, EstimateError> {
+        let colours = self.colours(game);
+        let maxima = self.maxima(&colours)?;
+        let log_weight = |counts: &[u32], log_likelihood: f64| {
+            log_likelihood
+                + counts
+                    .iter()
+                    .zip(&colours)
+                    .map(|(count, colour)| self.prior(colour).weight(*count).ln())
+                    .sum::<f64>()
+        };
+
+        // The first pass finds the peak, which the second scales by before leaving log space so
+        // nothing underflows to zero
+        let mut most_likely = vec![0; colours.len()];
+        let mut peak = f64::NEG_INFINITY;
+        self.for_each_bag(game, &colours, &maxima, |counts, log_likelihood| {
+            let log_weight = log_weight(counts, log_likelihood);
+            if log_weight > peak {
+                most_likely.copy_from_slice(counts);
+                peak = log_weight;
+            }
+        });
+        if peak == f64::NEG_INFINITY {
+            return Err(EstimateError::Impossible);
+        }
+
+        let mut marginals = maxima
+            .iter()
+            .map(|max| vec![0.0; *max as usize + 1])
+            .collect::<Vec<_>>();
+        let mut total = 0.0;
+        self.for_each_bag(game, &colours, &maxima, |counts, log_likelihood| {
+            let weight = (log_weight(counts, log_likelihood) - peak).exp();
+            total += weight;
+            for (marginal, count) in marginals.iter_mut().zip(counts) {
+                marginal[*count as usize] += weight;
+            }
+        });
+        for p in marginals.iter_mut().flatten() {
+            *p /= total;
+        }
+
+        Ok(Posterior {
+            marginals: colours
+                .iter()
+                .cloned()
+                .zip(marginals.into_iter().map(Distribution))
+                .collect(),
+            most_likely: to_bag(&colours, &most_likely),
+        })
+    }
+
+    // The bag that makes the draws most likely, ignoring the prior weights apart from which
+    // counts they allow. Ties go to the bag found first, which holds fewer cubes.
+    pub fn maximum_likelihood(&self, game: &Game) -> Result<Bag, EstimateError> {
+        let colours = self.colours(game);
+        let maxima = self.maxima(&colours)?;
+        let mut best = vec![0; colours.len()];
+        let mut peak = f64::NEG_INFINITY;
+
+        self.for_each_bag(game, &colours, &maxima, |counts, log_likelihood| {
+            let allowed = counts
+                .iter()
+                .zip(&colours)
+                .all(|(count, colour)| self.prior(colour).weight(*count) > 0.0);
+            if allowed && log_likelihood > peak {
+                best.copy_from_slice(counts);
+                peak = log_likelihood;
+            }
+        });
+
+        if peak == f64::NEG_INFINITY {
+            return Err(EstimateError::Impossible);
+        }
+        Ok(to_bag(&colours, &best))
+    }
+
+    // Checked before searching so the tables sized by the maxima stay bounded
+    fn maxima(&self, colours: &[String]) -> Result<Vec<u32>, EstimateError> {
+        let mut total = 0usize;
+        colours
+            .iter()
+            .map(|colour| {
+                let prior = self.prior(colour);
+                if !prior.is_valid() {
+                    return Err(EstimateError::InvalidWeights(colour.clone()));
+                }
+                total = total
+                    .checked_add(prior.max())
+                    .filter(|total| *total <= MAX_CUBES)
+                    .ok_or_else(|| EstimateError::TooManyCubes(colour.clone()))?;
+                Ok(prior.max() as u32)
+            })
+            .collect()
+    }
+
+    fn colours(&self, game: &Game) -> Vec<String> {
+        let mut colours = game
+            .draws
+            .iter()
+            .flat_map(|draw| draw.iter().map(|(colour, _)| colour.to_string()))
+            .chain(self.priors.keys().cloned())
+            .collect::<Vec<_>>();
+        colours.sort();
+        colours.dedup();
+        colours
+    }
+
+    // Visits every bag within the maxima, one per colour, with the log likelihood of the game's
+    // draws. This is a full grid search, so it grows with the product of the maxima.
+    fn for_each_bag(
+        &self,
+        game: &Game,
+        colours: &[String],
+        maxima: &[u32],
+        mut visit: impl FnMut(&[u32], f64),
+    ) {
+        let ln_factorials = ln_factorials(maxima.iter().map(|max| *max as usize).sum());
+        let ln_choose = |n: u32, k: u32| {
+            ln_factorials[n as usize] - ln_factorials[k as usize] - ln_factorials[(n - k) as usize]
+        };
+        let draws = game
+            .draws
+            .iter()
+            .map(|draw| {
+                colours
+                    .iter()
+                    .map(|colour| draw.count(colour))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut counts = vec![0; colours.len()];
+        loop {
+            let total = counts.iter().sum();
+            let log_likelihood = draws
+                .iter()
+                .map(|drawn| {
+                    // A colour drawn more times than the bag holds also makes the whole handful
+                    // too big, which would leave infinity minus infinity below
+                    if counts.iter().zip(drawn).any(|(n, k)| k > n) {
+                        return f64::NEG_INFINITY;
+                    }
+                    let handful = drawn.iter().sum();
+                    counts
+                        .iter()
+                        .zip(drawn)
+                        .map(|(n, k)| ln_choose(*n, *k))
+                        .sum::<f64>()
+                        - ln_choose(total, handful)
+                })
+                .sum();
+            visit(&counts, log_likelihood);
+
+            // Step to the next bag like an odometer, stopping once every colour wraps around
+            let Some(position) = (0..counts.len()).rev().find(|&i| counts[i] < maxima[i]) else {
+                break;
+            };
+            counts[position] += 1;
+            counts[position + 1..].fill(0);
+        }
+    }
+}
+
+fn ln_factorials(max: usize) -> Vec<f64> {
+    let mut table = vec![0.0; max + 1];
+    for n in 1..table.len() {
+        table[n] = table[n - 1] + (n as f64).ln();
+    }
+    table
+}
+
+fn to_bag(colours: &[String], counts: &[u32]) -> Bag {
+    Bag::new(
+        colours
+            .iter()
+            .cloned()
+            .zip(counts.iter().copied())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::parse_game;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_single_colour_draws_only_rule_out_small_bags() {
+        // A handful of only red cubes is certain whatever the bag holds, as long as it fits
+        let game = parse_game("Game 1: 2 red; 3 red").unwrap();
+        let estimator = Estimator {
+            default_prior: Prior::Uniform { max: 6 },
+            ..Default::default()
+        };
+        let posterior = estimator.posterior(&game).unwrap();
+        let red = posterior.colour("red").unwrap();
+
+        for count in 0..3 {
+            assert_eq!(red.probability(count), 0.0);
+        }
+        for count in 3..=6 {
+            assert!((red.probability(count) - 0.25).abs() < EPSILON);
+        }
+        assert!((red.mean() - 4.5).abs() < EPSILON);
+        assert_eq!(
+            estimator.maximum_likelihood(&game),
+            Ok(Bag::from([("red", 3)]))
+        );
+    }
+
+    #[test]
+    fn test_posterior_follows_the_draws() {
+        let game =
+            parse_game("Game 1: 3 red, 1 blue; 3 red, 1 blue; 2 red; 4 red, 1 blue").unwrap();
+        let estimator = Estimator {
+            default_prior: Prior::Uniform { max: 12 },
+            ..Default::default()
+        };
+        let posterior = estimator.posterior(&game).unwrap();
+        let red = posterior.colour("red").unwrap();
+        let blue = posterior.colour("blue").unwrap();
+
+        let total = (0..=12).map(|count| red.probability(count)).sum::<f64>();
+        assert!((total - 1.0).abs() < EPSILON);
+        assert_eq!(red.probability(3), 0.0);
+        assert_eq!(blue.probability(0), 0.0);
+        assert!(red.mean() > blue.mean());
+
+        let bag = estimator.maximum_likelihood(&game).unwrap();
+        assert!(bag.count("red") >= 4);
+        assert!(bag.count("red") > bag.count("blue"));
+    }
+
+    #[test]
+    fn test_priors_per_colour() {
+        let game = parse_game("Game 1: 1 red, 1 blue").unwrap();
+        let mut estimator = Estimator {
+            default_prior: Prior::Uniform { max: 5 },
+            ..Default::default()
+        };
+        // Someone counted the blue cubes, so only four is possible
+        estimator.priors.insert(
+            "blue".to_string(),
+            Prior::Weights(vec![0.0, 0.0, 0.0, 0.0, 1.0]),
+        );
+        let posterior = estimator.posterior(&game).unwrap();
+
+        assert!((posterior.colour("blue").unwrap().probability(4) - 1.0).abs() < EPSILON);
+        assert_eq!(posterior.most_likely_bag().count("blue"), 4);
+        assert_eq!(
+            estimator.maximum_likelihood(&game).unwrap().count("blue"),
+            4
+        );
+    }
+
+    #[test]
+    fn test_prior_colour_never_drawn() {
+        // Green cubes that never show up make bags with many of them less likely
+        let game = parse_game("Game 1: 2 red; 2 red; 2 red").unwrap();
+        let mut estimator = Estimator {
+            default_prior: Prior::Uniform { max: 4 },
+            ..Default::default()
+        };
+        estimator
+            .priors
+            .insert("green".to_string(), Prior::Uniform { max: 4 });
+        let green = estimator
+            .posterior(&game)
+            .unwrap()
+            .colour("green")
+            .unwrap()
+            .clone();
+
+        assert!(green.probability(0) > green.probability(4));
+        assert_eq!(green.mode(), 0);
+    }
+
+    #[test]
+    fn test_impossible_under_priors() {
+        let game = parse_game("Game 1: 9 red").unwrap();
+        let estimator = Estimator {
+            default_prior: Prior::Uniform { max: 8 },
+            ..Default::default()
+        };
+
+        assert_eq!(estimator.posterior(&game), Err(EstimateError::Impossible));
+        assert_eq!(
+            estimator.maximum_likelihood(&game),
+            Err(EstimateError::Impossible)
+        );
+    }
+
+    #[test]
+    fn test_invalid_weights() {
+        let game = parse_game("Game 1: 1 red, 1 blue").unwrap();
+
+        for weight in [-1.0, f64::NAN, f64::INFINITY] {
+            let mut estimator = Estimator {
+                default_prior: Prior::Uniform { max: 3 },
+                ..Default::default()
+            };
+            let prior = Prior::Weights(vec![0.0, 1.0, weight]);
+            assert!(!prior.is_valid());
+            estimator.priors.insert("blue".to_string(), prior);
+
+            let error = EstimateError::InvalidWeights("blue".to_string());
+            assert_eq!(estimator.posterior(&game), Err(error.clone()), "{}", weight);
+            assert_eq!(
+                estimator.maximum_likelihood(&game),
+                Err(error),
+                "{}",
+                weight
+            );
+        }
+        assert!(Prior::Weights(vec![0.0, 0.5]).is_valid());
+    }
+
+    #[test]
+    fn test_too_many_cubes() {
+        let game = parse_game("Game 1: 1 red, 1 blue").unwrap();
+        let estimator = Estimator {
+            default_prior: Prior::Uniform { max: u32::MAX },
+            ..Default::default()
+        };
+        let error = EstimateError::TooManyCubes("blue".to_string());
+
+        assert_eq!(estimator.posterior(&game), Err(error.clone()));
+        assert_eq!(estimator.maximum_likelihood(&game), Err(error));
+
+        // Each colour fits on its own but not together
+        let mut estimator = Estimator {
+            default_prior: Prior::Uniform {
+                max: MAX_CUBES as u32 / 2,
+            },
+            ..Default::default()
+        };
+        estimator
+            .priors
+            .insert("green".to_string(), Prior::Uniform { max: 1 });
+
+        assert_eq!(
+            estimator.posterior(&game),
+            Err(EstimateError::TooManyCubes("red".to_string()))
+        );
+    }
+}
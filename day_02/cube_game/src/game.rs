@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use crate::bag::Bag;
+
+// The cubes shown in one handful taken from the bag
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Draw(pub(crate) HashMap<String, u32>);
+
+impl Draw {
+    pub fn count(&self, colour: &str) -> u32 {
+        self.0.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.0
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+}
+
+impl<const N: usize> From<[(&str, u32); N]> for Draw {
+    fn from(counts: [(&str, u32); N]) -> Self {
+        Self(
+            counts
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+// A colour in a draw that needs more cubes than the bag holds
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    // Draws are numbered from zero in the order they appear in the game
+    pub draw: usize,
+    pub colour: String,
+    pub drawn: u32,
+    pub available: u32,
+}
+
+impl Game {
+    // The smallest bag every draw could have come from
+    pub fn minimal_bag(&self) -> Bag {
+        let mut bag = Bag::default();
+        for draw in &self.draws {
+            for (colour, count) in draw.iter() {
+                bag.raise(colour, count);
+            }
+        }
+        bag
+    }
+
+    pub fn power(&self, colours: &[&str]) -> u128 {
+        self.minimal_bag().power(colours)
+    }
+
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|draw| {
+            draw.iter()
+                .all(|(colour, count)| count <= bag.count(colour))
+        })
+    }
+
+    pub fn violations(&self, bag: &Bag) -> Vec<Violation> {
+        let mut violations = self
+            .draws
+            .iter()
+            .enumerate()
+            .flat_map(|(index, draw)| {
+                draw.iter()
+                    .filter(|(colour, count)| *count > bag.count(colour))
+                    .map(move |(colour, count)| Violation {
+                        draw: index,
+                        colour: colour.to_string(),
+                        drawn: count,
+                        available: bag.count(colour),
+                    })
+            })
+            .collect::<Vec<_>>();
+        // Colours within a draw come out of a HashMap, so put them in a stable order
+        violations.sort_by(|a, b| (a.draw, &a.colour).cmp(&(b.draw, &b.colour)));
+        violations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bag::STANDARD_COLOURS;
+    use crate::parser::{parse_game, parse_games};
+
+    const TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_minimal_bag() {
+        let game = parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+
+        assert_eq!(
+            game.minimal_bag(),
+            Bag::from([("red", 4), ("green", 2), ("blue", 6)])
+        );
+    }
+
+    #[test]
+    fn test_power() {
+        let powers = parse_games(TEST_INPUT)
+            .unwrap()
+            .iter()
+            .map(|game| game.power(&STANDARD_COLOURS))
+            .collect::<Vec<_>>();
+
+        assert_eq!(powers, vec![48, 12, 1560, 630, 36]);
+        // A colour that is never drawn needs no cubes
+        assert_eq!(
+            parse_game("Game 9: 2 red")
+                .unwrap()
+                .power(&STANDARD_COLOURS),
+            0
+        );
+    }
+
+    #[test]
+    fn test_is_possible() {
+        let possible = parse_games(TEST_INPUT)
+            .unwrap()
+            .iter()
+            .filter(|game| game.is_possible(&Bag::standard()))
+            .map(|game| game.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(possible, vec![1, 2, 5]);
+    }
+
+    #[test]
+    fn test_is_possible_needs_every_colour_in_the_bag() {
+        let game = parse_game("Game 7: 2 purple, 1 red; 3 purple").unwrap();
+
+        assert!(game.is_possible(&Bag::from([("purple", 3), ("red", 1)])));
+        assert!(!game.is_possible(&Bag::standard()));
+    }
+
+    #[test]
+    fn test_violations() {
+        let game =
+            parse_game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")
+                .unwrap();
+
+        assert_eq!(
+            game.violations(&Bag::from([("red", 12), ("green", 8), ("blue", 14)])),
+            vec![
+                Violation {
+                    draw: 0,
+                    colour: "red".to_string(),
+                    drawn: 20,
+                    available: 12,
+                },
+                Violation {
+                    draw: 1,
+                    colour: "green".to_string(),
+                    drawn: 13,
+                    available: 8,
+                },
+            ]
+        );
+        assert!(game.violations(&game.minimal_bag()).is_empty());
+    }
+}
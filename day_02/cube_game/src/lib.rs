@@ -0,0 +1,9 @@
+mod bag;
+mod estimate;
+mod game;
+mod parser;
+
+pub use bag::{Bag, STANDARD_COLOURS};
+pub use estimate::{Distribution, EstimateError, Estimator, Posterior, Prior, MAX_CUBES};
+pub use game::{Draw, Game, Violation};
+pub use parser::{parse_game, parse_games, ParseError, Reason};
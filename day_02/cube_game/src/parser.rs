@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::game::{Draw, Game};
+
+// Game lines follow this grammar, with spaces allowed after `:` and around `;` and `,`,
+// and whitespace around the whole line ignored:
+//
+//   game   = "Game" " "+ id ":" draw (";" draw)*
+//   draw   = cubes ("," cubes)*
+//   cubes  = count " "+ colour
+//   id     = digit+
+//   count  = digit+
+//   colour = letter+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    MissingGamePrefix,
+    MissingId,
+    InvalidId,
+    MissingColon,
+    MissingCount,
+    NegativeCount,
+    CountTooLarge,
+    MissingColour,
+    DuplicateColour(String),
+    UnexpectedCharacter(char),
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reason::MissingGamePrefix => write!(f, "expected `Game`"),
+            Reason::MissingId => write!(f, "expected a game id"),
+            Reason::InvalidId => write!(f, "game id must be a number that fits in 32 bits"),
+            Reason::MissingColon => write!(f, "expected `:` after the game id"),
+            Reason::MissingCount => write!(f, "expected a count of cubes"),
+            Reason::NegativeCount => write!(f, "counts can't be negative"),
+            Reason::CountTooLarge => write!(f, "count doesn't fit in 32 bits"),
+            Reason::MissingColour => write!(f, "expected a colour"),
+            Reason::DuplicateColour(colour) => write!(f, "{} appears twice in one draw", colour),
+            Reason::UnexpectedCharacter(c) => write!(f, "unexpected {:?}", c),
+        }
+    }
+}
+
+// Line and column numbers start from one, like in an editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub reason: Reason,
+    pub snippet: String,
+}
+
+impl ParseError {
+    fn new(line_number: usize, line: &str, offset: usize, reason: Reason) -> Self {
+        let column = line[..offset].chars().count() + 1;
+        Self {
+            line: line_number,
+            column,
+            reason,
+            snippet: format!("{}\n{}^", line, " ".repeat(column - 1)),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )?;
+        write!(f, "{}", self.snippet)
+    }
+}
+
+impl Error for ParseError {}
+
+// Blank lines are skipped, but still counted so errors point at the right line
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_line(index + 1, line))
+        .collect()
+}
+
+pub fn parse_game(input: &str) -> Result<Game, ParseError> {
+    parse_line(1, input)
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<Game, ParseError> {
+    let line = line.trim_end_matches('\r');
+    let mut cursor = Cursor {
+        line_number,
+        line,
+        offset: 0,
+    };
+
+    cursor.skip_spaces();
+    if !cursor.eat("Game") {
+        return Err(cursor.error(Reason::MissingGamePrefix));
+    }
+    if cursor.skip_spaces() == 0 {
+        return Err(cursor.error(Reason::MissingId));
+    }
+    let id = cursor.number(Reason::MissingId, Reason::InvalidId)?;
+    if !cursor.eat(":") {
+        return Err(cursor.error(Reason::MissingColon));
+    }
+
+    let mut draws = vec![parse_draw(&mut cursor)?];
+    while cursor.eat(";") {
+        draws.push(parse_draw(&mut cursor)?);
+    }
+    match cursor.peek() {
+        None => Ok(Game { id, draws }),
+        Some(c) => Err(cursor.error(Reason::UnexpectedCharacter(c))),
+    }
+}
+
+fn parse_draw(cursor: &mut Cursor) -> Result<Draw, ParseError> {
+    let mut counts = HashMap::new();
+    loop {
+        cursor.skip_spaces();
+        if cursor.peek() == Some('-') {
+            return Err(cursor.error(Reason::NegativeCount));
+        }
+        let count = cursor.number(Reason::MissingCount, Reason::CountTooLarge)?;
+        if cursor.skip_spaces() == 0 {
+            return Err(cursor.error(Reason::MissingColour));
+        }
+        let start = cursor.offset;
+        let colour = cursor.take_while(|c| c.is_alphabetic());
+        if colour.is_empty() {
+            return Err(cursor.error(Reason::MissingColour));
+        }
+        if counts.insert(colour.to_string(), count).is_some() {
+            return Err(cursor.error_at(start, Reason::DuplicateColour(colour.to_string())));
+        }
+        cursor.skip_spaces();
+        if !cursor.eat(",") {
+            return Ok(Draw(counts));
+        }
+    }
+}
+
+struct Cursor<'a> {
+    line_number: usize,
+    line: &'a str,
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn rest(&self) -> &'a str {
+        &self.line[self.offset..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        let found = self.rest().starts_with(expected);
+        if found {
+            self.offset += expected.len();
+        }
+        found
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.offset += length;
+        &rest[..length]
+    }
+
+    fn skip_spaces(&mut self) -> usize {
+        self.take_while(char::is_whitespace).len()
+    }
+
+    fn number(&mut self, missing: Reason, too_large: Reason) -> Result<u32, ParseError> {
+        let start = self.offset;
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            return Err(self.error(missing));
+        }
+        digits.parse().map_err(|_| self.error_at(start, too_large))
+    }
+
+    fn error(&self, reason: Reason) -> ParseError {
+        self.error_at(self.offset, reason)
+    }
+
+    fn error_at(&self, offset: usize, reason: Reason) -> ParseError {
+        ParseError::new(self.line_number, self.line, offset, reason)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    fn error(input: &str) -> (usize, Reason) {
+        let error = parse_game(input).unwrap_err();
+        (error.column, error.reason)
+    }
+
+    #[test]
+    fn test_parse_game_keeps_draws() {
+        let expected = Game {
+            id: 1,
+            draws: vec![
+                Draw::from([("blue", 3), ("red", 4)]),
+                Draw::from([("red", 1), ("green", 2), ("blue", 6)]),
+                Draw::from([("green", 2)]),
+            ],
+        };
+
+        assert_eq!(
+            parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_parse_game_any_colour() {
+        assert_eq!(
+            parse_game("Game 12: 2 yellow, 1 red").map(|game| game.draws),
+            Ok(vec![Draw::from([("yellow", 2), ("red", 1)])])
+        );
+    }
+
+    #[test]
+    fn test_parse_game_spacing() {
+        assert_eq!(
+            parse_game("  Game   3 :3 red ,4  blue;2 red  \r"),
+            Err(ParseError::new(
+                1,
+                "  Game   3 :3 red ,4  blue;2 red  ",
+                10,
+                Reason::MissingColon
+            ))
+        );
+        assert_eq!(
+            parse_game("  Game   3:3 red ,4  blue;2 red  \r"),
+            Ok(Game {
+                id: 3,
+                draws: vec![
+                    Draw::from([("red", 3), ("blue", 4)]),
+                    Draw::from([("red", 2)]),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_malformed_headers() {
+        assert_eq!(error("Gmae 1: 1 red"), (1, Reason::MissingGamePrefix));
+        assert_eq!(error("1: 1 red"), (1, Reason::MissingGamePrefix));
+        assert_eq!(error("Game1: 1 red"), (5, Reason::MissingId));
+        assert_eq!(error("Game : 1 red"), (6, Reason::MissingId));
+        assert_eq!(error("Game -1: 1 red"), (6, Reason::MissingId));
+        assert_eq!(error("Game 1a2: 1 red"), (7, Reason::MissingColon));
+        assert_eq!(error("Game 4294967296: 1 red"), (6, Reason::InvalidId));
+        assert_eq!(error("Game 1 1 red"), (7, Reason::MissingColon));
+    }
+
+    #[test]
+    fn test_malformed_counts() {
+        assert_eq!(error("Game 1: red"), (9, Reason::MissingCount));
+        assert_eq!(error("Game 1: 1 red, blue"), (16, Reason::MissingCount));
+        assert_eq!(error("Game 1: 1 red;"), (15, Reason::MissingCount));
+        assert_eq!(error("Game 1:"), (8, Reason::MissingCount));
+        assert_eq!(error("Game 1: 1 red; ; 2 red"), (16, Reason::MissingCount));
+        assert_eq!(error("Game 1: -3 red"), (9, Reason::NegativeCount));
+        assert_eq!(error("Game 1: 2 red, -3 blue"), (16, Reason::NegativeCount));
+        assert_eq!(error("Game 1: 99999999999 red"), (9, Reason::CountTooLarge));
+    }
+
+    #[test]
+    fn test_malformed_colours() {
+        assert_eq!(error("Game 1: 3"), (10, Reason::MissingColour));
+        assert_eq!(error("Game 1: 3 , 4 blue"), (11, Reason::MissingColour));
+        assert_eq!(error("Game 1: 3red"), (10, Reason::MissingColour));
+        assert_eq!(
+            error("Game 1: 3 red, 4 blue, 1 red"),
+            (26, Reason::DuplicateColour("red".to_string()))
+        );
+        assert_eq!(
+            error("Game 1: 3 red green"),
+            (15, Reason::UnexpectedCharacter('g'))
+        );
+        assert_eq!(
+            error("Game 1: 3 red. 4 blue"),
+            (14, Reason::UnexpectedCharacter('.'))
+        );
+    }
+
+    #[test]
+    fn test_same_colour_in_different_draws() {
+        assert!(parse_game("Game 1: 3 red; 4 red").is_ok());
+    }
+
+    #[test]
+    fn test_parse_games_reports_line() {
+        let input = indoc! {"
+            Game 1: 3 blue, 4 red
+
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 blue
+            Game 3: 8 green
+        "};
+        let error = parse_games(input).unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(
+            error.to_string(),
+            indoc! {"
+                line 3, column 45: blue appears twice in one draw
+                Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 blue
+                                                            ^"}
+        );
+    }
+
+    #[test]
+    fn test_parse_games_skips_blank_lines() {
+        let games = parse_games("Game 1: 1 red\n\n  \nGame 2: 2 blue\n").unwrap();
+
+        assert_eq!(
+            games.iter().map(|game| game.id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use cube_game::parse_games;
+
+pub use cube_game::{Bag, ParseError};
+
+pub fn sum_of_possible_games(input: &str, bag: &Bag) -> Result<u32, ParseError> {
+    Ok(parse_games(input)?
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_data_returns_correct_sum() {
+        let test_data = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let expected = 8;
+        let actual = sum_of_possible_games(test_data, &Bag::standard()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_sum_of_possible_games_with_other_bags() {
+        let test_data = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+
+        assert_eq!(sum_of_possible_games(test_data, &Bag::from([])), Ok(0));
+        assert_eq!(
+            sum_of_possible_games(
+                test_data,
+                &Bag::from([("red", 4), ("green", 3), ("blue", 6)])
+            ),
+            Ok(3)
+        );
+        assert_eq!(
+            sum_of_possible_games(
+                test_data,
+                &Bag::from([("red", 20), ("green", 13), ("blue", 6)])
+            ),
+            Ok(6)
+        );
+    }
+}
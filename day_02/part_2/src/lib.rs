@@ -0,0 +1,41 @@
+use cube_game::{parse_games, STANDARD_COLOURS};
+
+pub use cube_game::ParseError;
+
+// Saturates rather than overflowing, like the powers themselves
+pub fn sum_of_game_powers(input: &str) -> Result<u128, ParseError> {
+    Ok(parse_games(input)?
+        .iter()
+        .map(|game| game.power(&STANDARD_COLOURS))
+        .fold(0, u128::saturating_add))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_data_returns_correct_sum() {
+        let test_data = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let expected = 2286;
+        let actual = sum_of_game_powers(test_data).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_large_cube_counts() {
+        let test_data = "Game 1: 100000 red, 100000 green, 100000 blue
+        Game 2: 100000 red, 100000 green, 100000 blue";
+
+        assert_eq!(
+            sum_of_game_powers(test_data).unwrap(),
+            2_000_000_000_000_000
+        );
+    }
+}
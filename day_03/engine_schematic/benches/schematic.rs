@@ -0,0 +1,61 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use engine_schematic::{solve_part_with_symbol_search, Schematic};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SYMBOLS: [char; 6] = ['*', '#', '+', '$', '/', '@'];
+
+// A square schematic with roughly the same mix of numbers, symbols and dots as the puzzle
+fn generate_schematic(size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut schematic = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        let mut x = 0;
+        while x < size {
+            let roll = rng.gen_range(0..100);
+            let digits = rng.gen_range(1..=3);
+            // Numbers never touch the end of a line, the old approach would run them into
+            // the next line
+            if roll < 8 && x + digits < size {
+                // No leading zeros either, the old approach measures numbers by their value
+                schematic.push(char::from(b'0' + rng.gen_range(1..10)));
+                for _ in 1..digits {
+                    schematic.push(char::from(b'0' + rng.gen_range(0..10)));
+                }
+                schematic.push('.');
+                x += digits + 1;
+            } else if roll < 12 {
+                schematic.push(SYMBOLS[rng.gen_range(0..SYMBOLS.len())]);
+                x += 1;
+            } else {
+                schematic.push('.');
+                x += 1;
+            }
+        }
+        schematic.push('\n');
+    }
+    schematic
+}
+
+fn part_sum(c: &mut Criterion) {
+    let input = generate_schematic(1_000);
+    assert_eq!(
+        Schematic::from(input.as_str()).get_part_sum(),
+        u64::from(solve_part_with_symbol_search(&input))
+    );
+
+    let mut group = c.benchmark_group("part_sum");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("single_pass", |b| {
+        b.iter(|| Schematic::from(black_box(input.as_str())).get_part_sum())
+    });
+    group.bench_function("symbol_search", |b| {
+        b.iter(|| solve_part_with_symbol_search(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, part_sum);
+criterion_main!(benches);
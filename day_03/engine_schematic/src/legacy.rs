@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+
+use grid::{Coordinate, Grid};
+
+// The original approach, kept to benchmark the single pass against. It strips all whitespace
+// and assumes every line is as wide as the first, then searches around every symbol.
+pub fn solve_part_with_symbol_search(input: &str) -> u32 {
+    let mut schematic = Schematic::from(input);
+    schematic.calculate_parts();
+    schematic.get_part_sum()
+}
+
+trait EngineParts {
+    fn is_engine_part_symbol(&self) -> bool;
+}
+
+impl EngineParts for char {
+    #[inline]
+    fn is_engine_part_symbol(&self) -> bool {
+        matches!(*self, '!'..='-'| '/' | ':'..='@' | '['..='`' | '{'..='~')
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum SchematicElement {
+    Number(usize),
+    Symbol,
+}
+
+#[derive(Debug, PartialEq)]
+struct Number {
+    value: u32,
+    position: Coordinate,
+    is_part: bool,
+}
+
+impl Number {
+    fn new(value: u32, position: Coordinate) -> Number {
+        Number {
+            value,
+            position,
+            is_part: false,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.value.to_string().len()
+    }
+}
+
+struct Schematic {
+    numbers: Vec<Number>,
+    map: Grid<Option<SchematicElement>>,
+}
+
+fn get_numbers(s: &str, cols: usize) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    let mut current_number = String::new();
+    let mut start_index = None;
+
+    for (i, c) in s.char_indices() {
+        if c.is_numeric() {
+            current_number.push(c);
+            if start_index.is_none() {
+                start_index = Some(i);
+            }
+        } else if !current_number.is_empty() {
+            let n = Number::new(
+                current_number
+                    .parse::<u32>()
+                    .expect("Failed to Parse Number"),
+                get_position(cols, start_index.unwrap()),
+            );
+            numbers.push(n);
+            current_number.clear();
+            start_index = None;
+        }
+    }
+
+    if !current_number.is_empty() {
+        let n = Number::new(
+            current_number
+                .parse::<u32>()
+                .expect("Failed to Parse Number"),
+            get_position(cols, start_index.unwrap()),
+        );
+        numbers.push(n);
+    }
+
+    numbers
+}
+
+fn get_part_symbols(s: &str, cols: usize) -> Vec<Coordinate> {
+    let mut output = Vec::<Coordinate>::new();
+    for (i, _) in s.char_indices().filter(|&(_, c)| c.is_engine_part_symbol()) {
+        let position = get_position(cols, i);
+        output.push(position);
+    }
+
+    output
+}
+
+impl Schematic {
+    fn calculate_parts(&mut self) {
+        let mut to_update = HashSet::new();
+
+        for s in self
+            .map
+            .iter()
+            .filter(|(_, e)| matches!(e, Some(SchematicElement::Symbol)))
+        {
+            let adjacent_numbers = self.map.neighbours8(s.0).filter_map(|p| match self.map[p] {
+                Some(SchematicElement::Number(index)) => Some(index),
+                _ => None,
+            });
+
+            for number in adjacent_numbers {
+                to_update.insert(number);
+            }
+        }
+
+        for number in to_update {
+            self.numbers[number].is_part = true;
+        }
+    }
+
+    fn get_part_sum(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|n| n.is_part)
+            .map(|n| n.value)
+            .sum()
+    }
+}
+
+impl From<&str> for Schematic {
+    fn from(s: &str) -> Self {
+        let columns = s.lines().next().unwrap().len();
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+
+        let numbers = get_numbers(&s, columns);
+        let part_symbols = get_part_symbols(&s, columns);
+        let rows = s.len() / columns;
+        let mut map =
+            Grid::filled(columns, rows, None).expect("The map has no more cells than the input");
+        for (i, number) in numbers.iter().enumerate() {
+            let x = number.position.x;
+            for pos in x..x + number.len() {
+                let position = Coordinate::new(pos, number.position.y);
+                map[position] = Some(SchematicElement::Number(i));
+            }
+        }
+        for part_symbol in part_symbols.iter() {
+            map[*part_symbol] = Some(SchematicElement::Symbol);
+        }
+
+        Schematic { numbers, map }
+    }
+}
+
+fn get_position(columns: usize, index: usize) -> Coordinate {
+    let x = index % columns;
+    let y = index / columns;
+    Coordinate::new(x, y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_get_position_returns_correct_position_simple() {
+        let cols = 10;
+        let index = 1;
+
+        let expected = Coordinate::new(1, 0);
+        let actual = get_position(cols, index);
+
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn test_get_position_returns_correct_position_complex() {
+        let cols = 10;
+        let index = 23;
+
+        let expected = Coordinate::new(3, 2);
+        let actual = get_position(cols, index);
+
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn test_get_numbers_returns_expected_result() {
+        let test_data = "467..114";
+
+        let expected = [
+            Number::new(467, Coordinate::new(0, 0)),
+            Number::new(114, Coordinate::new(1, 1)),
+        ];
+        let expected_len = 2;
+
+        let actual = get_numbers(test_data, 4);
+        let actual_len = actual.len();
+
+        assert_eq!(expected_len, actual_len);
+        assert_eq!(expected[0], actual[0]);
+        assert_eq!(expected[1], actual[1]);
+    }
+
+    #[test]
+    fn test_get_part_symbols_returns_expected_result() {
+        let test_data = "467./114";
+
+        let expected = [Coordinate::new(0, 1)];
+        let expected_len = 1;
+
+        let actual = get_part_symbols(test_data, 4);
+        let actual_len = actual.len();
+
+        assert_eq!(expected_len, actual_len);
+        assert_eq!(expected[0], actual[0]);
+    }
+
+    #[test]
+    fn test_schematic_from_returns_expected_result() {
+        let test_data = "467.\n/114";
+
+        let expected_numbers = [
+            Number::new(467, Coordinate::new(0, 0)),
+            Number::new(114, Coordinate::new(1, 1)),
+        ];
+        let expected_numbers_len = 2;
+        let expected_map = [
+            (Coordinate::new(0, 0), SchematicElement::Number(0)),
+            (Coordinate::new(1, 1), SchematicElement::Number(1)),
+            (Coordinate::new(0, 1), SchematicElement::Symbol),
+        ];
+        let expected_map_len = 7;
+
+        let actual = Schematic::from(test_data);
+        let actual_numbers_len = actual.numbers.len();
+        let actual_map_len = actual.map.iter().filter(|(_, e)| e.is_some()).count();
+
+        assert_eq!(expected_numbers_len, actual_numbers_len);
+        assert_eq!(expected_map_len, actual_map_len);
+        assert_eq!(expected_numbers[0], actual.numbers[0]);
+        assert_eq!(expected_numbers[1], actual.numbers[1]);
+        assert!(actual.map[expected_map[0].0].is_some());
+        assert_eq!(Some(expected_map[0].1), actual.map[expected_map[0].0]);
+        assert!(actual.map[expected_map[1].0].is_some());
+        assert_eq!(Some(expected_map[1].1), actual.map[expected_map[1].0]);
+        assert!(actual.map[expected_map[2].0].is_some());
+        assert_eq!(Some(expected_map[2].1), actual.map[expected_map[2].0]);
+    }
+
+    #[test]
+    fn test_provided_test_data_returns_expected_result() {
+        let test_data = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+        let expected_sum = 4361;
+        let mut schematic = Schematic::from(test_data);
+        schematic.calculate_parts();
+        let actual_sum = schematic.get_part_sum();
+
+        assert_eq!(expected_sum, actual_sum);
+    }
+}
@@ -0,0 +1,10 @@
+mod legacy;
+mod render;
+mod rules;
+mod schematic;
+
+// Only public so the benchmark can compare against it
+#[doc(hidden)]
+pub use legacy::solve_part_with_symbol_search;
+pub use rules::{Aggregate, NeighbourCount, Rules};
+pub use schematic::{Gear, Number, Schematic, Symbol};
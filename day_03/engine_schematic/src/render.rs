@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use colored::*;
+use grid::Coordinate;
+
+use crate::schematic::{Schematic, SchematicElement};
+
+const HTML_HEADER: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Engine schematic</title>
+<style>
+body { background: #111; color: #555; font-family: monospace; }
+.part { color: #5d5; }
+.other { color: #e55; }
+.symbol { color: #59f; }
+.gear { color: #fd3; font-weight: bold; }
+</style>
+</head>
+<body>
+<pre>
+"#;
+
+const HTML_FOOTER: &str = "</pre>\n</body>\n</html>\n";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Cell {
+    Empty,
+    PartNumber(char),
+    OtherNumber(char),
+    Symbol(char),
+    Gear(char, u64),
+}
+
+impl Cell {
+    fn class(&self) -> Option<&'static str> {
+        match self {
+            Cell::Empty => None,
+            Cell::PartNumber(_) => Some("part"),
+            Cell::OtherNumber(_) => Some("other"),
+            Cell::Symbol(_) => Some("symbol"),
+            Cell::Gear(..) => Some("gear"),
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match *self {
+            Cell::Empty => '.',
+            Cell::PartNumber(c) | Cell::OtherNumber(c) | Cell::Symbol(c) | Cell::Gear(c, _) => c,
+        }
+    }
+}
+
+impl Schematic {
+    // Part numbers are green, other numbers red, symbols blue and gears yellow, followed by
+    // every gear's ratio
+    pub fn to_terminal(&self) -> String {
+        let mut output = String::new();
+
+        for row in self.annotated_cells() {
+            for cell in row {
+                let symbol = cell.symbol().to_string();
+                let colorized = match cell {
+                    Cell::Empty => symbol.dimmed(),
+                    Cell::PartNumber(_) => symbol.bright_green(),
+                    Cell::OtherNumber(_) => symbol.bright_red(),
+                    Cell::Symbol(_) => symbol.bright_blue(),
+                    Cell::Gear(..) => symbol.bright_yellow().bold(),
+                };
+                write!(output, "{}", colorized).unwrap();
+            }
+            output.push('\n');
+        }
+
+        for gear in self.gears() {
+            writeln!(
+                output,
+                "Gear at x: {}, y: {} has ratio {}",
+                gear.position.x, gear.position.y, gear.ratio
+            )
+            .unwrap();
+        }
+
+        output
+    }
+
+    // A standalone page with the same colours, hovering over a gear shows its ratio
+    pub fn to_html(&self) -> String {
+        let mut output = HTML_HEADER.to_string();
+
+        for row in self.annotated_cells() {
+            // Runs of cells with the same class share a span, gears get one each for their ratio
+            let mut cells = row.iter().peekable();
+            while let Some(cell) = cells.next() {
+                let mut text = escape(cell.symbol());
+                match (cell, cell.class()) {
+                    (Cell::Gear(_, ratio), Some(class)) => {
+                        write!(
+                            output,
+                            r#"<span class="{}" title="ratio {}">"#,
+                            class, ratio
+                        )
+                        .unwrap();
+                    }
+                    (_, Some(class)) => {
+                        while let Some(next) = cells.next_if(|next| {
+                            next.class() == cell.class() && !matches!(next, Cell::Gear(..))
+                        }) {
+                            text.push_str(&escape(next.symbol()));
+                        }
+                        write!(output, r#"<span class="{}">"#, class).unwrap();
+                    }
+                    (_, None) => {
+                        output.push_str(&text);
+                        continue;
+                    }
+                }
+                output.push_str(&text);
+                output.push_str("</span>");
+            }
+            output.push('\n');
+        }
+
+        output.push_str(HTML_FOOTER);
+        output
+    }
+
+    fn annotated_cells(&self) -> Vec<Vec<Cell>> {
+        let gears: HashMap<Coordinate, u64> = self
+            .gears()
+            .into_iter()
+            .map(|gear| (gear.position, gear.ratio))
+            .collect();
+
+        self.map
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, element)| match element {
+                        None => Cell::Empty,
+                        Some(SchematicElement::Number(index)) => {
+                            let digit = self.cells[Coordinate::new(x, y)];
+                            if self.numbers[*index].is_part {
+                                Cell::PartNumber(digit)
+                            } else {
+                                Cell::OtherNumber(digit)
+                            }
+                        }
+                        Some(SchematicElement::Symbol(c)) => {
+                            match gears.get(&Coordinate::new(x, y)) {
+                                Some(ratio) => Cell::Gear(*c, *ratio),
+                                None => Cell::Symbol(*c),
+                            }
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn escape(c: char) -> String {
+    match c {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        '"' => "&quot;".to_string(),
+        _ => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+    #[test]
+    fn test_cells() {
+        let cells = Schematic::from(TEST_DATA).annotated_cells();
+
+        assert_eq!(cells[0][0], Cell::PartNumber('4'));
+        assert_eq!(cells[0][5], Cell::OtherNumber('1'));
+        assert_eq!(cells[0][3], Cell::Empty);
+        assert_eq!(cells[1][3], Cell::Gear('*', 16345));
+        assert_eq!(cells[3][6], Cell::Symbol('#'));
+        // Only one part number next to it, so not a gear
+        assert_eq!(cells[4][3], Cell::Symbol('*'));
+    }
+
+    #[test]
+    fn test_to_terminal() {
+        let schematic = Schematic::from("12*3\n....");
+
+        colored::control::set_override(false);
+        let plain = schematic.to_terminal();
+        colored::control::set_override(true);
+        let coloured = schematic.to_terminal();
+        let gear = "*".bright_yellow().bold().to_string();
+        let part = "1".bright_green().to_string();
+        colored::control::unset_override();
+
+        assert_eq!(plain, "12*3\n....\nGear at x: 2, y: 0 has ratio 36\n");
+        assert_ne!(gear, "*");
+        assert!(coloured.contains(&gear));
+        assert!(coloured.contains(&part));
+    }
+
+    #[test]
+    fn test_to_html() {
+        let html = Schematic::from("12*3.45\n<&.....").to_html();
+        let body = html
+            .strip_prefix(HTML_HEADER)
+            .and_then(|html| html.strip_suffix(HTML_FOOTER))
+            .unwrap();
+
+        assert_eq!(
+            body,
+            concat!(
+                r#"<span class="part">12</span><span class="gear" title="ratio 36">*</span>"#,
+                r#"<span class="part">3</span>.<span class="other">45</span>"#,
+                "\n",
+                r#"<span class="symbol">&lt;&amp;</span>....."#,
+                "\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NeighbourCount {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl NeighbourCount {
+    pub fn matches(&self, count: usize) -> bool {
+        match *self {
+            NeighbourCount::Exactly(n) => count == n,
+            NeighbourCount::AtLeast(n) => count >= n,
+        }
+    }
+}
+
+// How the part numbers around a gear combine into its ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregate {
+    // Saturates at u64::MAX, which a gear with enough large parts around it can reach
+    pub fn apply(&self, values: impl Iterator<Item = u32>) -> u64 {
+        let mut values = values.map(u64::from);
+        match self {
+            Aggregate::Product => values.try_fold(1u64, u64::checked_mul).unwrap_or(u64::MAX),
+            Aggregate::Sum => values.fold(0, u64::saturating_add),
+            Aggregate::Max => values.max().unwrap_or(0),
+        }
+    }
+}
+
+// What counts as a part and what counts as a gear. The defaults are the puzzle's rules, where
+// any symbol other than `.` marks a part and a `*` next to exactly two parts is a gear.
+// Numbers only count towards a gear when they are parts, so gear symbols are normally part
+// symbols too.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules {
+    pub part_symbols: String,
+    pub gear_symbols: String,
+    pub neighbours: NeighbourCount,
+    pub aggregate: Aggregate,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            part_symbols: ('!'..='~')
+                .filter(|c| c.is_ascii_punctuation() && *c != '.')
+                .collect(),
+            gear_symbols: "*".to_string(),
+            neighbours: NeighbourCount::Exactly(2),
+            aggregate: Aggregate::Product,
+        }
+    }
+}
+
+impl Rules {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read rules {}", path.display()))?
+            .parse()
+            .with_context(|| format!("Failed to load rules {}", path.display()))
+    }
+
+    pub fn is_part_symbol(&self, c: char) -> bool {
+        self.part_symbols.contains(c)
+    }
+
+    pub fn is_gear_symbol(&self, c: char) -> bool {
+        self.gear_symbols.contains(c)
+    }
+}
+
+impl FromStr for Rules {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let rules: Rules = toml::from_str(s).context("Invalid rules")?;
+
+        for c in rules.part_symbols.chars().chain(rules.gear_symbols.chars()) {
+            if c.is_ascii_digit() || c == '.' || c.is_whitespace() {
+                bail!("{:?} can't be used as a symbol", c);
+            }
+        }
+
+        Ok(rules)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_default_rules() {
+        let rules = Rules::default();
+
+        assert!(rules.is_part_symbol('#'));
+        assert!(rules.is_part_symbol('*'));
+        assert!(!rules.is_part_symbol('.'));
+        assert!(!rules.is_part_symbol('7'));
+        assert!(rules.is_gear_symbol('*'));
+        assert!(!rules.is_gear_symbol('#'));
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules: Rules = indoc! {r#"
+            part_symbols = "*#$"
+            gear_symbols = "*#"
+            neighbours = { at_least = 1 }
+            aggregate = "max"
+        "#}
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            rules,
+            Rules {
+                part_symbols: "*#$".to_string(),
+                gear_symbols: "*#".to_string(),
+                neighbours: NeighbourCount::AtLeast(1),
+                aggregate: Aggregate::Max,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rules_keeps_defaults() {
+        let rules: Rules = "aggregate = \"sum\"".parse().unwrap();
+
+        assert_eq!(
+            rules,
+            Rules {
+                aggregate: Aggregate::Sum,
+                ..Rules::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!("part_symbols = \"*.\"".parse::<Rules>().is_err());
+        assert!("gear_symbols = \"1\"".parse::<Rules>().is_err());
+        assert!("neighbours = { around = 2 }".parse::<Rules>().is_err());
+        assert!("aggregate = \"mean\"".parse::<Rules>().is_err());
+        assert!("gears = \"*\"".parse::<Rules>().is_err());
+    }
+
+    #[test]
+    fn test_neighbour_count() {
+        assert!(NeighbourCount::Exactly(2).matches(2));
+        assert!(!NeighbourCount::Exactly(2).matches(3));
+        assert!(NeighbourCount::AtLeast(2).matches(3));
+        assert!(!NeighbourCount::AtLeast(2).matches(1));
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let values = [3, 5, 4];
+
+        assert_eq!(Aggregate::Product.apply(values.into_iter()), 60);
+        assert_eq!(Aggregate::Sum.apply(values.into_iter()), 12);
+        assert_eq!(Aggregate::Max.apply(values.into_iter()), 5);
+
+        let large = [u32::MAX; 3];
+        assert_eq!(Aggregate::Product.apply(large.into_iter()), u64::MAX);
+        assert_eq!(
+            Aggregate::Product.apply([0, u32::MAX, u32::MAX, u32::MAX].into_iter()),
+            0
+        );
+        assert_eq!(
+            Aggregate::Sum.apply(large.into_iter()),
+            3 * u64::from(u32::MAX)
+        );
+    }
+}
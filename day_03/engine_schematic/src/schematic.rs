@@ -0,0 +1,488 @@
+use grid::{Coordinate, Grid};
+
+use crate::rules::Rules;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum SchematicElement {
+    Number(usize),
+    Symbol(char),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Number {
+    pub value: u32,
+    // Where the first digit is
+    pub position: Coordinate,
+    // Counted from the text rather than the value, so leading zeros are kept
+    pub len: usize,
+    pub is_part: bool,
+}
+
+// A symbol and the numbers touching it, as indexes into `Schematic::numbers` in reading order
+#[derive(Debug, PartialEq, Clone)]
+pub struct Symbol {
+    pub position: Coordinate,
+    pub symbol: char,
+    pub numbers: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gear {
+    pub position: Coordinate,
+    pub symbol: char,
+    pub numbers: Vec<usize>,
+    pub ratio: u64,
+}
+
+pub struct Schematic {
+    pub(crate) numbers: Vec<Number>,
+    pub(crate) cells: Grid<char>,
+    pub(crate) map: Grid<Option<SchematicElement>>,
+    rules: Rules,
+}
+
+impl Schematic {
+    // Finds every number and whether it touches a part symbol in a single pass over the rows
+    pub fn new(s: &str, rules: Rules) -> Self {
+        let cells = read_cells(s);
+        // Looking symbols up in the rules' strings for every cell around every number is
+        // slow enough to show up on large schematics, so ASCII symbols get a table
+        let ascii_part_symbols = (0..128u8)
+            .map(|c| rules.is_part_symbol(char::from(c)))
+            .collect::<Vec<_>>();
+        let is_part_symbol = |c: char| match ascii_part_symbols.get(c as usize) {
+            Some(is_part_symbol) => *is_part_symbol,
+            None => rules.is_part_symbol(c),
+        };
+        let mut numbers = Vec::new();
+        let mut map = cells.map(|_| None);
+
+        for y in 0..cells.height() {
+            let row = cells.row(y);
+            let mut x = 0;
+            while x < row.len() {
+                if !row[x].is_ascii_digit() {
+                    if row[x] != '.' && !row[x].is_whitespace() {
+                        map[Coordinate::new(x, y)] = Some(SchematicElement::Symbol(row[x]));
+                    }
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                let mut value = Some(0u32);
+                while let Some(digit) = row.get(x).and_then(|c| c.to_digit(10)) {
+                    value = value
+                        .and_then(|value| value.checked_mul(10))
+                        .and_then(|value| value.checked_add(digit));
+                    x += 1;
+                }
+                // Runs of digits too long for a u32 are left as plain text rather than wrapping
+                let Some(value) = value else {
+                    continue;
+                };
+                for x in start..x {
+                    map[Coordinate::new(x, y)] = Some(SchematicElement::Number(numbers.len()));
+                }
+
+                let position = Coordinate::new(start, y);
+                let is_part =
+                    surrounding(&cells, position, x - start).any(|(_, c)| is_part_symbol(c));
+                numbers.push(Number {
+                    value,
+                    position,
+                    len: x - start,
+                    is_part,
+                });
+            }
+        }
+
+        Schematic {
+            numbers,
+            cells,
+            map,
+            rules,
+        }
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn get_part_sum(&self) -> u64 {
+        self.numbers
+            .iter()
+            .filter(|n| n.is_part)
+            .map(|n| u64::from(n.value))
+            .sum()
+    }
+
+    pub fn get_gear_ratio_sum(&self) -> u64 {
+        self.gears()
+            .iter()
+            .map(|gear| gear.ratio)
+            .fold(0, u64::saturating_add)
+    }
+
+    // Every gear symbol with the right number of part numbers around it. Numbers that aren't
+    // parts never count towards a gear.
+    pub fn gears(&self) -> Vec<Gear> {
+        self.symbols()
+            .into_iter()
+            .filter(|symbol| self.rules.is_gear_symbol(symbol.symbol))
+            .filter_map(|symbol| {
+                let numbers = symbol
+                    .numbers
+                    .into_iter()
+                    .filter(|&index| self.numbers[index].is_part)
+                    .collect::<Vec<_>>();
+                if !self.rules.neighbours.matches(numbers.len()) {
+                    return None;
+                }
+                let ratio = self
+                    .rules
+                    .aggregate
+                    .apply(numbers.iter().map(|&index| self.numbers[index].value));
+                Some(Gear {
+                    position: symbol.position,
+                    symbol: symbol.symbol,
+                    numbers,
+                    ratio,
+                })
+            })
+            .collect()
+    }
+
+    // Every symbol in reading order with the numbers around it
+    pub fn symbols(&self) -> Vec<Symbol> {
+        self.map
+            .iter()
+            .filter_map(|(position, element)| match element {
+                Some(SchematicElement::Symbol(symbol)) => Some(Symbol {
+                    position,
+                    symbol: *symbol,
+                    numbers: self.numbers_around(position),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn number_at(&self, position: Coordinate) -> Option<&Number> {
+        match self.map.get(position) {
+            Some(Some(SchematicElement::Number(index))) => Some(&self.numbers[*index]),
+            _ => None,
+        }
+    }
+
+    // The symbols touching a number in reading order, whether they mark parts or not
+    pub fn symbols_around(&self, number: &Number) -> Vec<(Coordinate, char)> {
+        surrounding(&self.cells, number.position, number.len)
+            .filter(|(position, _)| {
+                matches!(self.map[*position], Some(SchematicElement::Symbol(_)))
+            })
+            .collect()
+    }
+
+    fn numbers_around(&self, position: Coordinate) -> Vec<usize> {
+        let mut numbers = self
+            .map
+            .neighbours8(position)
+            .filter_map(|p| match self.map[p] {
+                Some(SchematicElement::Number(index)) => Some(index),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        numbers.sort();
+        numbers.dedup();
+        numbers
+    }
+}
+
+impl From<&str> for Schematic {
+    fn from(s: &str) -> Self {
+        Schematic::new(s, Rules::default())
+    }
+}
+
+// Lines can end in `\n` or `\r\n` and don't need to be the same width, short lines are
+// padded out with empty cells so the grid stays rectangular
+fn read_cells(s: &str) -> Grid<char> {
+    let lines = s
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .collect::<Vec<_>>();
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let rows = lines
+        .iter()
+        .map(|line| {
+            let mut row = line.chars().collect::<Vec<_>>();
+            row.resize(width, '.');
+            row
+        })
+        .collect();
+
+    Grid::from_rows(rows).expect("Rows are padded to the same width")
+}
+
+// The box of cells around a number including the diagonals, along with the number itself
+fn surrounding(
+    cells: &Grid<char>,
+    position: Coordinate,
+    len: usize,
+) -> impl Iterator<Item = (Coordinate, char)> + '_ {
+    let left = position.x.saturating_sub(1);
+    let right = (position.x + len).min(cells.width() - 1);
+    let top = position.y.saturating_sub(1);
+    let bottom = (position.y + 1).min(cells.height() - 1);
+
+    (top..=bottom)
+        .flat_map(move |y| (left..=right).map(move |x| (Coordinate::new(x, y), cells.row(y)[x])))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rules::{Aggregate, NeighbourCount};
+
+    const TEST_DATA: &str = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+    fn number(value: u32, x: usize, y: usize, len: usize, is_part: bool) -> Number {
+        Number {
+            value,
+            position: Coordinate::new(x, y),
+            len,
+            is_part,
+        }
+    }
+
+    #[test]
+    fn test_schematic_from_returns_expected_result() {
+        let test_data = "467.\n/114";
+
+        let expected_numbers = [number(467, 0, 0, 3, true), number(114, 1, 1, 3, true)];
+        let expected_map = [
+            (Coordinate::new(0, 0), SchematicElement::Number(0)),
+            (Coordinate::new(1, 1), SchematicElement::Number(1)),
+            (Coordinate::new(0, 1), SchematicElement::Symbol('/')),
+        ];
+        let expected_map_len = 7;
+
+        let actual = Schematic::from(test_data);
+        let actual_map_len = actual.map.iter().filter(|(_, e)| e.is_some()).count();
+
+        assert_eq!(actual.numbers, expected_numbers);
+        assert_eq!(expected_map_len, actual_map_len);
+        for (position, element) in expected_map {
+            assert_eq!(actual.map[position], Some(element));
+        }
+    }
+
+    #[test]
+    fn test_schematic_from_ragged_lines() {
+        let test_data = "467\n..*..\n.\n.35";
+
+        let actual = Schematic::from(test_data);
+
+        assert_eq!((actual.map.width(), actual.map.height()), (5, 4));
+        assert_eq!(
+            actual.numbers,
+            [number(467, 0, 0, 3, true), number(35, 1, 3, 2, false)]
+        );
+    }
+
+    #[test]
+    fn test_schematic_from_crlf() {
+        let test_data = "467..\r\n...*.\r\n..35.\r\n";
+
+        let actual = Schematic::from(test_data);
+
+        assert_eq!((actual.map.width(), actual.map.height()), (5, 3));
+        assert_eq!(actual.get_part_sum(), 502);
+        assert_eq!(actual.get_gear_ratio_sum(), 16345);
+    }
+
+    #[test]
+    fn test_numbers_stop_at_the_end_of_a_line() {
+        let test_data = "...12\n34...\n....#";
+
+        let actual = Schematic::from(test_data);
+
+        assert_eq!(
+            actual.numbers,
+            [number(12, 3, 0, 2, false), number(34, 0, 1, 2, false)]
+        );
+    }
+
+    #[test]
+    fn test_leading_zeros_count_towards_the_width() {
+        let test_data = "007.\n...$";
+
+        assert_eq!(
+            Schematic::from(test_data).numbers,
+            [number(7, 0, 0, 3, true)]
+        );
+    }
+
+    #[test]
+    fn test_numbers_too_long_for_u32_are_skipped() {
+        let schematic = Schematic::from("4294967295*\n4294967296*\n12345678901#");
+
+        assert_eq!(schematic.numbers, vec![number(4294967295, 0, 0, 10, true)]);
+        assert_eq!(schematic.get_part_sum(), 4294967295);
+    }
+
+    #[test]
+    fn test_part_sum_past_u32() {
+        let schematic = Schematic::from("4000000000*4000000000");
+
+        assert_eq!(schematic.get_part_sum(), 8_000_000_000);
+    }
+
+    #[test]
+    fn test_empty_schematic() {
+        let schematic = Schematic::from("");
+
+        assert_eq!(schematic.get_part_sum(), 0);
+        assert_eq!(schematic.get_gear_ratio_sum(), 0);
+    }
+
+    #[test]
+    fn test_symbols() {
+        let symbols = Schematic::from(TEST_DATA).symbols();
+
+        assert_eq!(symbols.len(), 6);
+        assert_eq!(
+            symbols[0],
+            Symbol {
+                position: Coordinate::new(3, 1),
+                symbol: '*',
+                numbers: vec![0, 2],
+            }
+        );
+        assert_eq!(
+            symbols
+                .iter()
+                .map(|symbol| symbol.numbers.len())
+                .collect::<Vec<_>>(),
+            vec![2, 1, 1, 1, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_gears() {
+        let schematic = Schematic::from(TEST_DATA);
+        let gears = schematic.gears();
+
+        assert_eq!(
+            gears
+                .iter()
+                .map(|gear| (gear.position, gear.ratio))
+                .collect::<Vec<_>>(),
+            vec![
+                (Coordinate::new(3, 1), 16345),
+                (Coordinate::new(5, 8), 451490)
+            ]
+        );
+        assert_eq!(schematic.numbers[gears[1].numbers[0]].value, 755);
+    }
+
+    #[test]
+    fn test_gears_with_rules() {
+        let rules = Rules {
+            gear_symbols: "*#".to_string(),
+            neighbours: NeighbourCount::AtLeast(1),
+            aggregate: Aggregate::Sum,
+            ..Rules::default()
+        };
+
+        assert_eq!(
+            Schematic::new(TEST_DATA, rules).get_gear_ratio_sum(),
+            502 + 633 + 617 + 1353
+        );
+    }
+
+    #[test]
+    fn test_gear_numbers_must_be_parts() {
+        // `*` is a gear but doesn't mark parts, so nothing around it counts
+        let rules = Rules {
+            part_symbols: "#".to_string(),
+            ..Rules::default()
+        };
+
+        assert_eq!(
+            Schematic::new("12*3\n....", rules.clone()).get_gear_ratio_sum(),
+            0
+        );
+        assert_eq!(
+            Schematic::new("12*3\n#...", rules.clone()).get_gear_ratio_sum(),
+            0
+        );
+        assert_eq!(Schematic::new("12*3\n#..#", rules).get_gear_ratio_sum(), 36);
+    }
+
+    #[test]
+    fn test_gear_with_many_large_parts() {
+        let input = "99999.99999\n99999*99999\n99999.99999\n";
+        let rules = |aggregate| Rules {
+            neighbours: NeighbourCount::AtLeast(2),
+            aggregate,
+            ..Rules::default()
+        };
+
+        let schematic = Schematic::new(input, rules(Aggregate::Product));
+        assert_eq!(schematic.gears()[0].numbers.len(), 6);
+        assert_eq!(schematic.get_gear_ratio_sum(), u64::MAX);
+
+        let doubled = format!("{}{}", input, input);
+        assert_eq!(
+            Schematic::new(&doubled, rules(Aggregate::Product)).get_gear_ratio_sum(),
+            u64::MAX
+        );
+        assert_eq!(
+            Schematic::new(input, rules(Aggregate::Sum)).get_gear_ratio_sum(),
+            6 * 99999
+        );
+    }
+
+    #[test]
+    fn test_number_at() {
+        let schematic = Schematic::from(TEST_DATA);
+
+        assert_eq!(
+            schematic.number_at(Coordinate::new(7, 0)),
+            Some(&number(114, 5, 0, 3, false))
+        );
+        assert_eq!(schematic.number_at(Coordinate::new(3, 1)), None);
+        assert_eq!(schematic.number_at(Coordinate::new(30, 1)), None);
+    }
+
+    #[test]
+    fn test_symbols_around() {
+        let schematic = Schematic::from(TEST_DATA);
+        let symbols_around = |x, y| {
+            let number = schematic.number_at(Coordinate::new(x, y)).unwrap();
+            schematic.symbols_around(number)
+        };
+
+        assert_eq!(symbols_around(0, 0), vec![(Coordinate::new(3, 1), '*')]);
+        assert_eq!(symbols_around(5, 0), vec![]);
+        assert_eq!(symbols_around(2, 4), vec![(Coordinate::new(3, 4), '*')]);
+    }
+
+    #[test]
+    fn test_provided_test_data_returns_expected_result() {
+        let schematic = Schematic::from(TEST_DATA);
+
+        assert_eq!(schematic.get_part_sum(), 4361);
+        assert_eq!(schematic.get_gear_ratio_sum(), 467835);
+    }
+}
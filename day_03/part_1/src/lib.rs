@@ -0,0 +1,19 @@
+use engine_schematic::Schematic;
+
+pub fn solve_part(input: &str) -> u64 {
+    Schematic::from(input).get_part_sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_provided_test_data_returns_expected_result() {
+        let test_data = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+        let expected_sum = 4361;
+        let actual_sum = solve_part(test_data);
+
+        assert_eq!(expected_sum, actual_sum);
+    }
+}
@@ -0,0 +1,86 @@
+use engine_schematic::Schematic;
+
+pub use engine_schematic::{Aggregate, NeighbourCount, Rules};
+
+pub fn solve_part(input: &str) -> u64 {
+    solve_part_with_rules(input, &Rules::default())
+}
+
+pub fn solve_part_with_rules(input: &str, rules: &Rules) -> u64 {
+    Schematic::new(input, rules.clone()).get_gear_ratio_sum()
+}
+
+pub fn render_terminal(input: &str, rules: &Rules) -> String {
+    Schematic::new(input, rules.clone()).to_terminal()
+}
+
+pub fn render_html(input: &str, rules: &Rules) -> String {
+    Schematic::new(input, rules.clone()).to_html()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_provided_test_data_returns_expected_result() {
+        let test_data = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+        let expected_sum = 4361;
+        let expected_ratio_sum = 467835;
+        let schematic = Schematic::from(test_data);
+
+        assert_eq!(expected_sum, schematic.get_part_sum());
+        assert_eq!(expected_ratio_sum, schematic.get_gear_ratio_sum());
+        assert_eq!(expected_ratio_sum, solve_part(test_data));
+    }
+
+    #[test]
+    fn test_gear_rules() {
+        let test_data = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+        assert_eq!(solve_part(test_data), 467835);
+        assert_eq!(
+            solve_part_with_rules(
+                test_data,
+                &Rules {
+                    aggregate: Aggregate::Sum,
+                    ..Rules::default()
+                }
+            ),
+            1855
+        );
+        assert_eq!(
+            solve_part_with_rules(
+                test_data,
+                &Rules {
+                    neighbours: NeighbourCount::AtLeast(1),
+                    aggregate: Aggregate::Max,
+                    ..Rules::default()
+                }
+            ),
+            1839
+        );
+        assert_eq!(
+            solve_part_with_rules(
+                test_data,
+                &Rules {
+                    gear_symbols: "*#".to_string(),
+                    neighbours: NeighbourCount::Exactly(1),
+                    aggregate: Aggregate::Sum,
+                    ..Rules::default()
+                }
+            ),
+            1250
+        );
+    }
+
+    #[test]
+    fn test_dots_are_not_symbols() {
+        // Only dots surround 114, so it isn't a part and can't be part of a gear
+        let test_data = "114.\n....\n..*.\n.35.";
+        let schematic = Schematic::from(test_data);
+
+        assert_eq!(schematic.get_part_sum(), 35);
+        assert_eq!(solve_part(test_data), 0);
+    }
+}
@@ -0,0 +1,31 @@
+use scratchcards::parse_cards;
+
+pub use scratchcards::{Report, Rules};
+
+pub fn solve_part(input: &str) -> u64 {
+    Rules::part_1().play(&parse_cards(input)).points
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_provided_test_data_returns_expected_result() {
+        let test_data = indoc! {"
+            Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "};
+
+        assert_eq!(solve_part(test_data), 13);
+        assert_eq!(
+            Report::new(&parse_cards(test_data), &Rules::part_1()).total_points,
+            13
+        );
+    }
+}
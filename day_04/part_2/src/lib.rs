@@ -0,0 +1,48 @@
+use scratchcards::parse_cards;
+
+pub use scratchcards::{Report, Rules};
+
+pub fn solve_part(input: &str) -> u128 {
+    Rules::part_2().cards(&parse_cards(input))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use scratchcards::count_cards;
+
+    #[test]
+    fn test_provided_test_data_returns_expected_result() {
+        let test_data = indoc! {"
+            Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "};
+
+        assert_eq!(solve_part(test_data), 30);
+        assert_eq!(count_cards::<u128>(test_data), 30);
+    }
+
+    #[test]
+    fn test_card_with_more_than_64_matches() {
+        let numbers: Vec<String> = (1..=69).map(|n| n.to_string()).collect();
+        let test_data = format!("Card 1: {0} | {0}\nCard 2: 1 | 2\n", numbers.join(" "));
+
+        assert_eq!(solve_part(&test_data), 3);
+    }
+
+    #[test]
+    fn test_copies_follow_matches_not_points() {
+        // The first card has four matches, worth eight points, so it wins the next four cards
+        let test_data: String =
+            std::iter::once("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n".to_string())
+                .chain((2..=9).map(|card| format!("Card {}: 1 | 2\n", card)))
+                .collect();
+
+        assert_eq!(solve_part(&test_data), 13);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub number: usize,
+    pub winners: Vec<u32>,
+    pub plays: Vec<u32>,
+}
+
+impl Card {
+    pub fn parse(line: &str) -> Card {
+        let (head, tail) = line
+            .split_once(':')
+            .expect("Game should be in format Game x : xxx");
+        let (winners, plays) = tail
+            .split_once('|')
+            .expect("Game should be in format: x x | x x");
+
+        Card {
+            number: parse_card_number(head),
+            winners: parse_numbers_list(winners),
+            plays: parse_numbers_list(plays),
+        }
+    }
+
+    // Winning numbers found among the plays, in the order they are listed. A number listed twice
+    // only counts once.
+    pub fn matched(&self) -> Vec<u32> {
+        let plays: HashSet<u32> = self.plays.iter().copied().collect();
+        let mut seen = HashSet::new();
+        self.winners
+            .iter()
+            .copied()
+            .filter(|n| plays.contains(n) && seen.insert(*n))
+            .collect()
+    }
+
+    pub fn matches(&self) -> usize {
+        self.matched().len()
+    }
+}
+
+// Cards are returned in card number order, whatever order they were listed in
+pub fn parse_cards(input: &str) -> Vec<Card> {
+    let mut cards: Vec<Card> = input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(Card::parse)
+        .collect();
+    cards.sort_unstable_by_key(|c| c.number);
+    cards
+}
+
+fn parse_card_number(input: &str) -> usize {
+    input
+        .split_whitespace()
+        .nth(1)
+        .expect("Game head should be two parts long")
+        .parse::<usize>()
+        .expect("Failed to parse card as usize")
+}
+
+fn parse_numbers_list(input: &str) -> Vec<u32> {
+    input
+        .split_whitespace()
+        .map(|n| {
+            n.parse::<u32>()
+                .expect("Input numbers should be in a valid number format")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_card_number_returns_correctly_single_digit() {
+        let test_data = "Card 3";
+        let expected = 3;
+        let actual = parse_card_number(test_data);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_card_number_returns_correctly_multi_digit() {
+        let test_data = "Card 111";
+        let expected = 111;
+        let actual = parse_card_number(test_data);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_card_matches() {
+        let card = Card::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
+
+        assert_eq!(card.number, 1);
+        assert_eq!(card.matched(), vec![48, 83, 86, 17]);
+        assert_eq!(card.matches(), 4);
+    }
+
+    #[test]
+    fn test_repeated_winning_numbers_match_once() {
+        let card = Card::parse("Card 1: 5 5 7 | 5 7 7");
+
+        assert_eq!(card.matched(), vec![5, 7]);
+    }
+
+    #[test]
+    fn test_parse_cards_orders_by_number() {
+        let cards = parse_cards("Card 2: 1 | 1\n\nCard 1: 2 | 3\n");
+
+        assert_eq!(
+            cards.iter().map(|c| c.number).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+}
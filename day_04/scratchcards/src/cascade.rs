@@ -0,0 +1,99 @@
+use crate::card::{parse_cards, Card};
+use crate::rules::{CardCount, CopyRule};
+
+// The number of cards held can grow exponentially with the number of cards, so the total type is
+// left to the caller: u64 covers the puzzle, u128 covers much longer tables. Totals too big for
+// the type saturate at its maximum, as in the per card report.
+pub fn count_cards<T: CardCount>(input: &str) -> T {
+    let matches: Vec<usize> = parse_cards(input).iter().map(Card::matches).collect();
+    cascade(&matches)
+}
+
+// Total cards held under the puzzle's copy rule
+pub fn cascade<T: CardCount>(matches: &[usize]) -> T {
+    CopyRule::Capped.total(matches)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::report::Report;
+    use crate::rules::Rules;
+
+    // Hands out every copy one card at a time, as the original solution did
+    fn cascade_by_copy(matches: &[usize]) -> u128 {
+        let mut copies = vec![1u128; matches.len()];
+        for (i, &matches) in matches.iter().enumerate() {
+            for k in i + 1..(i + 1 + matches).min(copies.len()) {
+                copies[k] += copies[i];
+            }
+        }
+        copies.iter().sum()
+    }
+
+    fn card(number: usize, matches: usize) -> String {
+        let winners: Vec<String> = (1..=matches.max(1)).map(|n| n.to_string()).collect();
+        let plays: Vec<String> = (1..=matches)
+            .map(|n| n.to_string())
+            .chain((0..5).map(|n| (1_000_000 + n).to_string()))
+            .collect();
+        format!(
+            "Card {}: {} | {}",
+            number,
+            winners.join(" "),
+            plays.join(" ")
+        )
+    }
+
+    #[test]
+    fn test_wins_past_the_last_card_are_dropped() {
+        let test_data = [card(1, 3), card(2, 5)].join("\n");
+
+        assert_eq!(count_cards::<u64>(&test_data), 3);
+    }
+
+    #[test]
+    fn test_cascade_handles_large_tables() {
+        // The first card wins a copy of every other card and the rest win the card after them, so
+        // card n ends up with about 2n copies and the total no longer fits in a u32
+        let matches: Vec<usize> = (0..70_000)
+            .map(|i| if i == 0 { 69_999 } else { 1 })
+            .collect();
+        let test_data: Vec<String> = matches
+            .iter()
+            .enumerate()
+            .map(|(i, &m)| card(i + 1, m))
+            .collect();
+        let test_data = test_data.join("\n");
+
+        let expected = cascade_by_copy(&matches);
+        assert!(expected > u32::MAX as u128);
+        assert_eq!(count_cards::<u128>(&test_data), expected);
+        assert_eq!(count_cards::<u64>(&test_data) as u128, expected);
+    }
+
+    #[test]
+    fn test_cascade_overflowing_u64_fits_in_u128() {
+        // Two matches a card doubles the copies like the Fibonacci numbers
+        let matches = vec![2; 100];
+
+        assert_eq!(cascade::<u128>(&matches), cascade_by_copy(&matches));
+        assert!(cascade::<u128>(&matches) > u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_cascade_past_u64_saturates() {
+        // Each card wins every card after it, so the last of 71 cards holds 2^70 copies
+        let test_data: String = (1..=71)
+            .map(|card| {
+                let numbers: Vec<String> = (1..=71 - card).map(|n| n.to_string()).collect();
+                format!("Card {0}: {1} | {1}\n", card, numbers.join(" "))
+            })
+            .collect();
+        let report = Report::new(&parse_cards(&test_data), &Rules::part_2());
+
+        assert_eq!(count_cards::<u64>(&test_data), u64::MAX);
+        assert_eq!(count_cards::<u128>(&test_data), (1 << 71) - 1);
+        assert_eq!(count_cards::<u128>(&test_data), report.total_copies);
+    }
+}
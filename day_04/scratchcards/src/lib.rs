@@ -0,0 +1,9 @@
+mod card;
+mod cascade;
+mod report;
+mod rules;
+
+pub use card::{parse_cards, Card};
+pub use cascade::{cascade, count_cards};
+pub use report::{CardReport, Format, Report, Source};
+pub use rules::{CardCount, CopyRule, Custom, Doubling, Linear, Outcome, Rules, ScoringRule};
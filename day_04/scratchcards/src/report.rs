@@ -0,0 +1,341 @@
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::card::{parse_cards, Card};
+use crate::rules::Rules;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Source {
+    pub card: usize,
+    pub copies: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CardReport {
+    pub card: usize,
+    pub matched: Vec<u32>,
+    pub points: u64,
+    pub copies: u128,
+    // Cards that won copies of this one and how many each handed on, in the order they were played.
+    // The copies held are the original plus all of these.
+    pub won_from: Vec<Source>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub cards: Vec<CardReport>,
+    pub total_points: u64,
+    pub total_copies: u128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(arg: &str) -> Result<Self, Self::Err> {
+        match arg {
+            "--table" => Ok(Format::Table),
+            "--json" => Ok(Format::Json),
+            other => Err(format!(
+                "unknown argument {}, expected --table or --json",
+                other
+            )),
+        }
+    }
+}
+
+impl Report {
+    // Unlike `CopyRule::copies` this records every card a copy came from, so it takes time
+    // proportional to the total number of matches. Totals saturate rather than overflow.
+    pub fn new(cards: &[Card], rules: &Rules) -> Report {
+        let mut reports: Vec<CardReport> = cards
+            .iter()
+            .map(|c| CardReport {
+                card: c.number,
+                points: rules.scoring.points(&c.matched()),
+                matched: c.matched(),
+                copies: 1,
+                won_from: Vec::new(),
+            })
+            .collect();
+
+        for i in 0..reports.len() {
+            let source = Source {
+                card: reports[i].card,
+                copies: reports[i].copies,
+            };
+            for k in rules.copies.won(i, reports[i].matched.len(), reports.len()) {
+                reports[k].copies = reports[k].copies.saturating_add(source.copies);
+                reports[k].won_from.push(source.clone());
+            }
+        }
+
+        Report {
+            total_points: reports
+                .iter()
+                .map(|r| r.points)
+                .fold(0, u64::saturating_add),
+            total_copies: reports
+                .iter()
+                .map(|r| r.copies)
+                .fold(0, u128::saturating_add),
+            cards: reports,
+        }
+    }
+
+    // Reports for each `--table` or `--json` argument, in the order given
+    pub fn render_args<I>(args: I, cards: &[Card], rules: &Rules) -> Result<String, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let formats = args
+            .into_iter()
+            .map(|arg| arg.parse::<Format>())
+            .collect::<Result<Vec<_>, _>>()?;
+        if formats.is_empty() {
+            return Ok(String::new());
+        }
+
+        let report = Report::new(cards, rules);
+        Ok(formats.into_iter().map(|f| report.render(f)).collect())
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Table => self.to_table(),
+            Format::Json => format!("{}\n", self.to_json()),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Report should serialize to JSON")
+    }
+
+    pub fn to_table(&self) -> String {
+        let rows: Vec<[String; 5]> = self
+            .cards
+            .iter()
+            .map(|r| {
+                let matched: Vec<String> = r.matched.iter().map(|n| n.to_string()).collect();
+                let won_from: Vec<String> = r
+                    .won_from
+                    .iter()
+                    .map(|s| format!("{} (x{})", s.card, s.copies))
+                    .collect();
+                [
+                    r.card.to_string(),
+                    matched.join(" "),
+                    r.points.to_string(),
+                    r.copies.to_string(),
+                    won_from.join(", "),
+                ]
+            })
+            .collect();
+        let header = ["Card", "Matched", "Points", "Copies", "Won from"].map(String::from);
+        let total = [
+            "Total".to_string(),
+            String::new(),
+            self.total_points.to_string(),
+            self.total_copies.to_string(),
+            String::new(),
+        ];
+
+        let mut widths = [0; 5];
+        for row in std::iter::once(&header).chain(&rows).chain([&total]) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let line = |row: &[String; 5]| {
+            let line = format!(
+                "{:<w0$} | {:<w1$} | {:>w2$} | {:>w3$} | {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                row[4],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+            );
+            format!("{}\n", line.trim_end())
+        };
+        // The outer columns have a single space of padding
+        let rule: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, w)| "-".repeat(if i == 0 || i == 4 { w + 1 } else { w + 2 }))
+            .collect();
+        let rule = format!("{}\n", rule.join("+"));
+
+        let mut table = line(&header);
+        table.push_str(&rule);
+        rows.iter().for_each(|row| table.push_str(&line(row)));
+        table.push_str(&rule);
+        table.push_str(&line(&total));
+        table
+    }
+}
+
+impl From<&str> for Report {
+    fn from(input: &str) -> Self {
+        Report::new(&parse_cards(input), &Rules::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rules::{CopyRule, Linear};
+    use indoc::indoc;
+
+    const TEST_DATA: &str = indoc! {"
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+    "};
+
+    #[test]
+    fn test_report_traces_the_cascade() {
+        let report = Report::from(TEST_DATA);
+
+        assert_eq!(report.total_points, 13);
+        assert_eq!(report.total_copies, 30);
+        assert_eq!(
+            report.cards.iter().map(|r| r.copies).collect::<Vec<_>>(),
+            vec![1, 2, 4, 8, 14, 1]
+        );
+        assert_eq!(
+            report.cards[4],
+            CardReport {
+                card: 5,
+                matched: vec![],
+                points: 0,
+                copies: 14,
+                won_from: vec![
+                    Source { card: 1, copies: 1 },
+                    Source { card: 3, copies: 4 },
+                    Source { card: 4, copies: 8 },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_report_table() {
+        let expected = indoc! {"
+            Card  | Matched     | Points | Copies | Won from
+            ------+-------------+--------+--------+-----------------------
+            1     | 48 83 86 17 |      8 |      1 |
+            2     | 32 61       |      2 |      2 | 1 (x1)
+            3     | 1 21        |      2 |      4 | 1 (x1), 2 (x2)
+            4     | 84          |      1 |      8 | 1 (x1), 2 (x2), 3 (x4)
+            5     |             |      0 |     14 | 1 (x1), 3 (x4), 4 (x8)
+            6     |             |      0 |      1 |
+            ------+-------------+--------+--------+-----------------------
+            Total |             |     13 |     30 |
+        "};
+
+        assert_eq!(Report::from(TEST_DATA).to_table(), expected);
+    }
+
+    #[test]
+    fn test_report_json() {
+        let report = Report::from("Card 1: 1 2 | 2 3\nCard 2: 4 | 5");
+        let expected = indoc! {r#"
+            {
+              "cards": [
+                {
+                  "card": 1,
+                  "matched": [
+                    2
+                  ],
+                  "points": 1,
+                  "copies": 1,
+                  "won_from": []
+                },
+                {
+                  "card": 2,
+                  "matched": [],
+                  "points": 0,
+                  "copies": 2,
+                  "won_from": [
+                    {
+                      "card": 1,
+                      "copies": 1
+                    }
+                  ]
+                }
+              ],
+              "total_points": 1,
+              "total_copies": 3
+            }"#};
+
+        assert_eq!(report.to_json(), expected);
+    }
+
+    #[test]
+    fn test_report_follows_rules() {
+        let rules = Rules {
+            scoring: Box::new(Linear { per_match: 5 }),
+            copies: CopyRule::WrapAround,
+        };
+        let report = Report::new(&parse_cards("Card 1: 1 | 2\nCard 2: 1 2 | 1 2"), &rules);
+
+        assert_eq!(report.total_points, 10);
+        assert_eq!(report.total_copies, 3);
+        assert_eq!(
+            report.cards[0].won_from,
+            vec![Source { card: 2, copies: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_report_past_u64() {
+        // Each card wins every card after it, so card k holds 2^(k - 1) copies
+        let input: String = (1..=71)
+            .map(|card| {
+                let numbers: Vec<String> = (1..=71 - card).map(|n| n.to_string()).collect();
+                format!("Card {0}: {1} | {1}\n", card, numbers.join(" "))
+            })
+            .collect();
+        let report = Report::from(input.as_str());
+
+        assert_eq!(report.cards[70].copies, 1 << 70);
+        assert_eq!(report.total_copies, (1 << 71) - 1);
+        assert_eq!(report.cards[0].points, u64::MAX);
+        assert_eq!(report.total_points, u64::MAX);
+    }
+
+    #[test]
+    fn test_render_args() {
+        let cards = parse_cards("Card 1: 1 2 | 2 3\nCard 2: 4 | 5");
+        let rules = Rules::default();
+        let args = |args: &[&str]| args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            Report::render_args(args(&[]), &cards, &rules),
+            Ok(String::new())
+        );
+        let report = Report::new(&cards, &rules);
+        assert_eq!(
+            Report::render_args(args(&["--table", "--json"]), &cards, &rules),
+            Ok(format!("{}{}\n", report.to_table(), report.to_json()))
+        );
+        assert_eq!(
+            Report::render_args(args(&["--json", "--csv"]), &cards, &rules),
+            Err("unknown argument --csv, expected --table or --json".to_string())
+        );
+    }
+}
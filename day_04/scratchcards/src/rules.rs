@@ -0,0 +1,417 @@
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingSub};
+use num_traits::{Bounded, SaturatingAdd};
+
+use crate::card::Card;
+
+// A type copies can be counted in. Counts too big for it saturate at its maximum, the same as in
+// the per card report, so u64 and u128 only differ in how long a table they count exactly.
+pub trait CardCount:
+    Copy + From<u32> + Bounded + OverflowingAdd + OverflowingSub + SaturatingAdd
+{
+}
+
+impl<T> CardCount for T where
+    T: Copy + From<u32> + Bounded + OverflowingAdd + OverflowingSub + SaturatingAdd
+{
+}
+
+// A running sum of copies. Each card's count is either exact, which fits in T, or capped past
+// T's maximum. The exact counts carry on past the maximum by counting how often they wrapped and
+// the capped ones are only counted, so every count added can be taken off again exactly.
+#[derive(Clone, Copy)]
+struct Total<T> {
+    low: T,
+    wraps: usize,
+    capped: usize,
+}
+
+impl<T: CardCount> Total<T> {
+    fn new(value: u32) -> Self {
+        Total {
+            low: T::from(value),
+            wraps: 0,
+            capped: 0,
+        }
+    }
+
+    fn add(&mut self, other: Total<T>) {
+        let (low, carry) = self.low.overflowing_add(&other.low);
+        self.low = low;
+        self.wraps += other.wraps + usize::from(carry);
+        self.capped += other.capped;
+    }
+
+    fn sub(&mut self, other: Total<T>) {
+        let (low, borrow) = self.low.overflowing_sub(&other.low);
+        self.low = low;
+        self.wraps -= other.wraps + usize::from(borrow);
+        self.capped -= other.capped;
+    }
+
+    // One more than the total, as a single card's count
+    fn and_one(&self) -> Total<T> {
+        let (low, carry) = self.low.overflowing_add(&T::from(1));
+        if self.wraps > 0 || self.capped > 0 || carry {
+            Total {
+                low: T::from(0),
+                wraps: 0,
+                capped: 1,
+            }
+        } else {
+            Total {
+                low,
+                wraps: 0,
+                capped: 0,
+            }
+        }
+    }
+
+    fn saturated(&self) -> T {
+        if self.wraps > 0 || self.capped > 0 {
+            T::max_value()
+        } else {
+            self.low
+        }
+    }
+}
+
+pub trait ScoringRule {
+    // Points for one copy of a card given the winning numbers it matched
+    fn points(&self, matched: &[u32]) -> u64;
+}
+
+// The first match is worth a point and every further match doubles it, saturating at u64::MAX
+// past 64 matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Doubling;
+
+impl ScoringRule for Doubling {
+    fn points(&self, matched: &[u32]) -> u64 {
+        match matched.len() {
+            0 => 0,
+            n => u32::try_from(n - 1)
+                .ok()
+                .and_then(|shift| 1u64.checked_shl(shift))
+                .unwrap_or(u64::MAX),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Linear {
+    pub per_match: u64,
+}
+
+impl ScoringRule for Linear {
+    fn points(&self, matched: &[u32]) -> u64 {
+        (matched.len() as u64).saturating_mul(self.per_match)
+    }
+}
+
+pub struct Custom<F>(pub F);
+
+impl<F: Fn(&[u32]) -> u64> ScoringRule for Custom<F> {
+    fn points(&self, matched: &[u32]) -> u64 {
+        (self.0)(matched)
+    }
+}
+
+// Which cards a card with n matches wins copies of. Every copy held wins the same cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CopyRule {
+    // Cards only score, nothing is won
+    None,
+    // The next n cards, wins past the last card are lost
+    #[default]
+    Capped,
+    // The next n cards, carrying on from the first card past the last one. The table is only played
+    // through once, so copies won of cards that have already been played add to their count but
+    // don't win anything themselves. A card never wins a copy of itself.
+    WrapAround,
+}
+
+impl CopyRule {
+    // Indices of the cards won by the card at `index`
+    pub fn won(&self, index: usize, matches: usize, cards: usize) -> Vec<usize> {
+        match self {
+            CopyRule::None => Vec::new(),
+            CopyRule::Capped => (index + 1..(index + 1 + matches).min(cards)).collect(),
+            CopyRule::WrapAround => (1..=matches.min(cards.saturating_sub(1)))
+                .map(|k| (index + k) % cards)
+                .collect(),
+        }
+    }
+
+    // Copies held of each card. All copies of a card are handed on together: the count is added to
+    // a running total when the card is reached and taken off again once its run of won cards ends,
+    // so each card is visited once however many copies it has.
+    pub fn copies<T: CardCount>(&self, matches: &[usize]) -> Vec<T> {
+        let cards = matches.len();
+        let mut held = Vec::with_capacity(cards);
+        let mut expiring = vec![Total::new(0); cards + 1];
+        // Copies wrapped round to the start, by the end of the run they cover
+        let mut wrapped = vec![Total::new(0); cards + 1];
+        let mut won = Total::<T>::new(0);
+
+        for (i, &matches) in matches.iter().enumerate() {
+            won.sub(expiring[i]);
+            let copies = won.and_one();
+            held.push(copies);
+
+            let matches = match self {
+                CopyRule::None => 0,
+                CopyRule::Capped => matches,
+                CopyRule::WrapAround => matches.min(cards - 1),
+            };
+            let end = (i + 1 + matches).min(cards);
+            if end > i + 1 {
+                won.add(copies);
+                expiring[end].add(copies);
+            }
+            if *self == CopyRule::WrapAround && i + 1 + matches > cards {
+                wrapped[i + 1 + matches - cards].add(copies);
+            }
+        }
+
+        // A run wrapped round to `end` covers every card before it
+        let mut late = Total::new(0);
+        for i in (0..cards).rev() {
+            late.add(wrapped[i + 1]);
+            held[i].add(late);
+        }
+
+        held.iter().map(Total::saturated).collect()
+    }
+
+    // The total number of cards held, saturating like the counts of each card
+    pub fn total<T: CardCount>(&self, matches: &[usize]) -> T {
+        self.copies::<T>(matches)
+            .into_iter()
+            .fold(T::from(0), |total, held| total.saturating_add(&held))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    // Points scored by each card, counted once however many copies are held
+    pub points: u64,
+    pub cards: u128,
+}
+
+pub struct Rules {
+    pub scoring: Box<dyn ScoringRule>,
+    pub copies: CopyRule,
+}
+
+impl Rules {
+    pub fn part_1() -> Rules {
+        Rules {
+            scoring: Box::new(Doubling),
+            copies: CopyRule::None,
+        }
+    }
+
+    pub fn part_2() -> Rules {
+        Rules {
+            scoring: Box::new(Doubling),
+            copies: CopyRule::Capped,
+        }
+    }
+
+    pub fn play(&self, cards: &[Card]) -> Outcome {
+        Outcome {
+            points: self.points(cards),
+            cards: self.cards(cards),
+        }
+    }
+
+    // Total points, saturating rather than overflowing
+    pub fn points(&self, cards: &[Card]) -> u64 {
+        cards
+            .iter()
+            .map(|c| self.scoring.points(&c.matched()))
+            .fold(0, u64::saturating_add)
+    }
+
+    // Total cards held once every copy has been won, without scoring any of them
+    pub fn cards<T: CardCount>(&self, cards: &[Card]) -> T {
+        let matches: Vec<usize> = cards.iter().map(Card::matches).collect();
+        self.copies.total(&matches)
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::part_2()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::card::parse_cards;
+    use crate::report::Report;
+    use indoc::indoc;
+
+    const TEST_DATA: &str = indoc! {"
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+    "};
+
+    // Hands out every copy one card at a time
+    fn copies_by_copy(rule: CopyRule, matches: &[usize]) -> Vec<u64> {
+        let mut copies = vec![1; matches.len()];
+        for (i, &m) in matches.iter().enumerate() {
+            for k in rule.won(i, m, matches.len()) {
+                copies[k] += copies[i];
+            }
+        }
+        copies
+    }
+
+    #[test]
+    fn test_scoring_rules() {
+        let points = |rule: &dyn ScoringRule| -> Vec<u64> {
+            [&[][..], &[4], &[4, 9], &[4, 9, 2]]
+                .iter()
+                .map(|m| rule.points(m))
+                .collect()
+        };
+
+        assert_eq!(points(&Doubling), vec![0, 1, 2, 4]);
+        assert_eq!(Doubling.points(&[7; 64]), 1 << 63);
+        assert_eq!(Doubling.points(&[7; 65]), u64::MAX);
+        assert_eq!(points(&Linear { per_match: 3 }), vec![0, 3, 6, 9]);
+        assert_eq!(
+            points(&Custom(|m: &[u32]| m.iter().map(|&n| n as u64).sum())),
+            vec![0, 4, 13, 15]
+        );
+    }
+
+    #[test]
+    fn test_presets_solve_both_parts() {
+        let cards = parse_cards(TEST_DATA);
+
+        assert_eq!(
+            Rules::part_1().play(&cards),
+            Outcome {
+                points: 13,
+                cards: 6
+            }
+        );
+        assert_eq!(
+            Rules::part_2().play(&cards),
+            Outcome {
+                points: 13,
+                cards: 30
+            }
+        );
+    }
+
+    #[test]
+    fn test_copy_rules() {
+        let matches = [4, 2, 2, 1, 0, 0];
+
+        assert_eq!(CopyRule::None.copies::<u64>(&matches), vec![1; 6]);
+        assert_eq!(
+            CopyRule::Capped.copies::<u64>(&matches),
+            vec![1, 2, 4, 8, 14, 1]
+        );
+        assert_eq!(CopyRule::Capped.copies::<u64>(&[0, 3, 1]), vec![1, 1, 2]);
+        assert_eq!(CopyRule::WrapAround.won(4, 3, 6), vec![5, 0, 1]);
+        assert_eq!(
+            CopyRule::WrapAround.copies::<u64>(&[0, 3, 1]),
+            vec![4, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_linear_copies_match_handing_out_each_copy() {
+        let matches: Vec<usize> = (0..60).map(|i| (i * 7 + 3) % 5).collect();
+
+        for rule in [CopyRule::None, CopyRule::Capped, CopyRule::WrapAround] {
+            assert_eq!(
+                rule.copies::<u64>(&matches),
+                copies_by_copy(rule, &matches),
+                "{:?}",
+                rule
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let cards = parse_cards(TEST_DATA);
+        let rules = Rules {
+            scoring: Box::new(Linear { per_match: 1 }),
+            copies: CopyRule::WrapAround,
+        };
+
+        assert_eq!(
+            rules.play(&cards),
+            Outcome {
+                points: 9,
+                cards: 30
+            }
+        );
+    }
+
+    #[test]
+    fn test_cards_with_more_than_64_matches() {
+        let winners: Vec<String> = (1..=69).map(|n| n.to_string()).collect();
+        let input = format!("Card 1: {0} | {0}\nCard 2: 1 | 2", winners.join(" "));
+        let cards = parse_cards(&input);
+
+        assert_eq!(Rules::part_2().cards::<u64>(&cards), 3);
+        assert_eq!(
+            Rules::part_2().play(&cards),
+            Outcome {
+                points: u64::MAX,
+                cards: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_copies_saturate_like_the_report() {
+        // Each card wins every card after it, so card k holds 2^(k - 1) copies
+        let input: String = (1..=130)
+            .map(|card| {
+                let numbers: Vec<String> = (1..=130 - card).map(|n| n.to_string()).collect();
+                format!("Card {0}: {1} | {1}\n", card, numbers.join(" "))
+            })
+            .collect();
+        let cards = parse_cards(&input);
+        let matches: Vec<usize> = cards.iter().map(Card::matches).collect();
+        let report = Report::new(&cards, &Rules::part_2());
+
+        let copies = CopyRule::Capped.copies::<u64>(&matches);
+        assert_eq!(copies[63], 1 << 63);
+        assert_eq!(copies[64], u64::MAX);
+        assert_eq!(CopyRule::Capped.total::<u64>(&matches), u64::MAX);
+        assert_eq!(
+            CopyRule::Capped.copies::<u128>(&matches),
+            report.cards.iter().map(|r| r.copies).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            CopyRule::Capped.total::<u128>(&matches),
+            report.total_copies
+        );
+        assert_eq!(Rules::part_2().cards::<u128>(&cards), u128::MAX);
+    }
+
+    #[test]
+    fn test_wrapped_copies_saturate() {
+        let matches = vec![3; 100];
+        let exact = CopyRule::WrapAround.copies::<u128>(&matches);
+        let saturated = CopyRule::WrapAround.copies::<u64>(&matches);
+
+        for (exact, saturated) in exact.iter().zip(&saturated) {
+            assert_eq!(u128::from(*saturated), (*exact).min(u128::from(u64::MAX)));
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use lib::generator::{generate, GeneratorConfig};
+
+fn seed_ranges(c: &mut Criterion) {
+    let mut group = c.benchmark_group("seed_ranges");
+    group.sample_size(10);
+
+    for max_seed_range_length in [1_000, 10_000, 100_000] {
+        let almanac = generate(&GeneratorConfig {
+            max_seed_range_length,
+            ..GeneratorConfig::default()
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("brute_force", max_seed_range_length),
+            &almanac,
+            |b, almanac| {
+                b.iter(|| {
+                    black_box(almanac)
+                        .calculate_seed_locations_with_seed_ranges()
+                        .unwrap()
+                        .into_iter()
+                        .min()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("ranges", max_seed_range_length),
+            &almanac,
+            |b, almanac| {
+                b.iter(|| {
+                    black_box(almanac)
+                        .calculate_location_ranges_with_seed_ranges()
+                        .unwrap()
+                        .first()
+                        .map(|range| range.start)
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, seed_ranges);
+criterion_main!(benches);
@@ -1,53 +1,288 @@
 use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::fmt;
+use std::ops::Range;
 
 use crate::converter::Converter;
-use crate::parser::parse_almanac;
+use crate::diagnostic::{Diagnostic, Problem};
+use crate::parser::{parse_almanac, ParseError};
+use crate::stage::Stage;
+
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+#[derive(Debug, PartialEq)]
+pub enum ChainError {
+    UnknownCategory(String),
+    Broken { from: String, to: String },
+    Ambiguous { from: String, to: String },
+    // A map on the chain runs past usize::MAX
+    Overflow(Diagnostic),
+    // Seed ranges need a start and a length for every range
+    UnpairedSeeds(usize),
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChainError::UnknownCategory(category) => {
+                write!(f, "No map mentions the {} category", category)
+            }
+            ChainError::Broken { from, to } => {
+                write!(f, "No chain of maps converts {} to {}", from, to)
+            }
+            ChainError::Ambiguous { from, to } => {
+                write!(f, "More than one chain of maps converts {} to {}", from, to)
+            }
+            ChainError::Overflow(diagnostic) => write!(f, "{}", diagnostic),
+            ChainError::UnpairedSeeds(count) => {
+                write!(f, "Seeds must be in pairs, found {} seeds", count)
+            }
+        }
+    }
+}
+
+impl Error for ChainError {}
 
 #[derive(Debug, PartialEq)]
 pub struct Almanac {
     pub seeds: Vec<usize>,
-    pub seed_to_soil_map: Converter,
-    pub soil_to_fertilizer_map: Converter,
-    pub fertilizer_to_water_map: Converter,
-    pub water_to_light_map: Converter,
-    pub light_to_temperature_map: Converter,
-    pub temperature_to_humidity_map: Converter,
-    pub humidity_to_location_map: Converter,
+    pub stages: Vec<Stage>,
 }
 
 impl Almanac {
-    pub fn from_string(input: &str) -> Result<Almanac, Box<dyn Error + '_>> {
+    pub fn new(seeds: Vec<usize>, stages: Vec<Stage>) -> Self {
+        Self { seeds, stages }
+    }
+
+    pub fn from_string(input: &str) -> Result<Almanac, ParseError> {
         parse_almanac(input)
     }
 
-    pub fn calculate_seed_locations(&self) -> Vec<usize> {
-        self.seeds
+    pub fn calculate_seed_locations(&self) -> Result<Vec<usize>, ChainError> {
+        let converter = self.compose(SEED, LOCATION)?;
+        Ok(self
+            .seeds
             .iter()
-            .map(|seed| self.convert_seed_to_location(*seed))
-            .collect()
+            .map(|seed| converter.lookup(*seed))
+            .collect())
     }
 
-    pub fn calculate_seed_locations_with_seed_ranges(&self) -> Vec<usize> {
-        assert!(self.seeds.len() % 2 == 0, "Seeds must be in pairs");
-        self.seeds
-            .par_chunks(2)
-            .flat_map(|pair| {
-                (pair[0]..pair[0] + pair[1])
+    pub fn calculate_seed_locations_with_seed_ranges(&self) -> Result<Vec<usize>, ChainError> {
+        let seed_ranges = self.seed_ranges()?;
+        let converter = self.compose(SEED, LOCATION)?;
+        Ok(seed_ranges
+            .into_par_iter()
+            .flat_map(|range| {
+                let converter = &converter;
+                range
                     .into_par_iter()
-                    .map(move |seed| self.convert_seed_to_location(seed))
+                    .map(move |seed| converter.lookup(seed))
             })
-            .collect()
+            .collect())
+    }
+
+    // Converts whole seed ranges stage by stage instead of every individual seed
+    pub fn calculate_location_ranges_with_seed_ranges(
+        &self,
+    ) -> Result<Vec<Range<usize>>, ChainError> {
+        let seed_ranges = self.seed_ranges()?;
+        let chain = self.chain(SEED, LOCATION)?;
+
+        Ok(convert_ranges_through(&chain, seed_ranges))
+    }
+
+    // Seeds read as pairs of a start and a length, with ranges running past usize::MAX cut short
+    pub fn seed_ranges(&self) -> Result<Vec<Range<usize>>, ChainError> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(ChainError::UnpairedSeeds(self.seeds.len()));
+        }
+        Ok(self
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0].saturating_add(pair[1]))
+            .collect())
+    }
+
+    pub fn convert(&self, from: &str, to: &str, value: usize) -> Result<usize, ChainError> {
+        let chain = self.chain(from, to)?;
+        Ok(convert_through(&chain, value))
+    }
+
+    pub fn convert_range(
+        &self,
+        from: &str,
+        to: &str,
+        range: Range<usize>,
+    ) -> Result<Vec<Range<usize>>, ChainError> {
+        let chain = self.chain(from, to)?;
+        Ok(convert_ranges_through(&chain, vec![range]))
+    }
+
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        self.stages.iter().flat_map(Stage::validate).collect()
+    }
+
+    // Collapses every stage between two categories into one flattened converter
+    pub fn compose(&self, from: &str, to: &str) -> Result<Converter, ChainError> {
+        let chain = self.chain(from, to)?;
+        Ok(chain
+            .iter()
+            .fold(Converter::new(Vec::new()), |converter, stage| {
+                converter.compose(&stage.converter)
+            }))
+    }
+
+    pub fn invert(&self, from: &str, to: &str) -> Result<Converter, ChainError> {
+        Ok(self.compose(from, to)?.invert())
+    }
+
+    // Every range of values in the from category that converts into the range in the
+    // to category, e.g. every seed that lands at a location
+    pub fn preimage(
+        &self,
+        from: &str,
+        to: &str,
+        range: Range<usize>,
+    ) -> Result<Vec<Range<usize>>, ChainError> {
+        let chain = self.chain(from, to)?;
+        Ok(chain
+            .iter()
+            .rev()
+            .fold(merge_ranges(vec![range]), |ranges, stage| {
+                merge_ranges(
+                    ranges
+                        .into_iter()
+                        .flat_map(|range| stage.converter.preimage(range))
+                        .collect(),
+                )
+            }))
+    }
+
+    // Finds the stages that lead from one category to another, following the graph of
+    // categories built from the section headers. There must be exactly one such chain.
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<&Stage>, ChainError> {
+        for category in [from, to] {
+            if !self.has_category(category) {
+                return Err(ChainError::UnknownCategory(category.to_string()));
+            }
+        }
+
+        let mut graph: HashMap<&str, Vec<&Stage>> = HashMap::new();
+        for stage in &self.stages {
+            graph.entry(stage.source.as_str()).or_default().push(stage);
+        }
+
+        let mut chains = Vec::new();
+        let mut visited = HashSet::from([from]);
+        find_chains(&graph, from, to, &mut visited, &mut Vec::new(), &mut chains);
+
+        match chains.len() {
+            0 => Err(ChainError::Broken {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+            1 => {
+                let chain = chains.remove(0);
+                let overflow = chain
+                    .iter()
+                    .flat_map(|stage| stage.validate())
+                    .find(|diagnostic| matches!(diagnostic.problem, Problem::Overflow { .. }));
+                match overflow {
+                    Some(diagnostic) => Err(ChainError::Overflow(diagnostic)),
+                    None => Ok(chain),
+                }
+            }
+            _ => Err(ChainError::Ambiguous {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+        }
+    }
+
+    fn has_category(&self, category: &str) -> bool {
+        self.stages
+            .iter()
+            .any(|stage| stage.source == category || stage.destination == category)
+    }
+}
+
+// Depth first search for every chain between two categories, stopping once a second
+// chain proves the conversion is ambiguous
+fn find_chains<'a: 'v, 'v>(
+    graph: &HashMap<&str, Vec<&'a Stage>>,
+    current: &str,
+    to: &str,
+    visited: &mut HashSet<&'v str>,
+    chain: &mut Vec<&'a Stage>,
+    chains: &mut Vec<Vec<&'a Stage>>,
+) {
+    if current == to {
+        chains.push(chain.clone());
+        return;
+    }
+
+    for stage in graph.get(current).into_iter().flatten() {
+        if chains.len() > 1 {
+            return;
+        }
+        if !visited.insert(stage.destination.as_str()) {
+            continue;
+        }
+        chain.push(stage);
+        find_chains(graph, &stage.destination, to, visited, chain, chains);
+        chain.pop();
+        visited.remove(stage.destination.as_str());
+    }
+}
+
+fn convert_through(chain: &[&Stage], value: usize) -> usize {
+    chain
+        .iter()
+        .fold(value, |value, stage| stage.converter.convert(value))
+}
+
+fn convert_ranges_through(chain: &[&Stage], ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    chain.iter().fold(merge_ranges(ranges), |ranges, stage| {
+        merge_ranges(
+            ranges
+                .into_iter()
+                .flat_map(|range| stage.converter.convert_range(range))
+                .collect(),
+        )
+    })
+}
+
+// Sorts the ranges and joins any that overlap or touch, so the number of ranges
+// carried between stages stays bounded by the number of maps
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
     }
+    merged
+}
 
-    fn convert_seed_to_location(&self, seed: usize) -> usize {
-        let mut res = self.seed_to_soil_map.convert(seed);
-        res = self.soil_to_fertilizer_map.convert(res);
-        res = self.fertilizer_to_water_map.convert(res);
-        res = self.water_to_light_map.convert(res);
-        res = self.light_to_temperature_map.convert(res);
-        res = self.temperature_to_humidity_map.convert(res);
-        self.humidity_to_location_map.convert(res)
+// The puzzle's own format, which parses back into an equal almanac
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in &self.seeds {
+            write!(f, " {}", seed)?;
+        }
+        writeln!(f)?;
+        for stage in &self.stages {
+            writeln!(f)?;
+            writeln!(f, "{}", stage)?;
+        }
+        Ok(())
     }
 }
 
@@ -55,45 +290,428 @@ impl Almanac {
 mod test {
     use super::*;
     use crate::map::Map;
+    use indoc::indoc;
     use lazy_static::lazy_static;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
 
     lazy_static! {
-        static ref TEST_ALMANAC: Almanac = Almanac {
-            seeds: vec![79, 14, 55, 13],
-            seed_to_soil_map: Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
-            soil_to_fertilizer_map: Converter::new(vec![
-                Map::new(0, 15, 37),
-                Map::new(37, 52, 2),
-                Map::new(39, 0, 15),
-            ]),
-            fertilizer_to_water_map: Converter::new(vec![
-                Map::new(49, 53, 8),
-                Map::new(0, 11, 42),
-                Map::new(42, 0, 7),
-                Map::new(57, 7, 4),
-            ]),
-            water_to_light_map: Converter::new(vec![Map::new(88, 18, 7), Map::new(18, 25, 70)]),
-            light_to_temperature_map: Converter::new(vec![
-                Map::new(45, 77, 23),
-                Map::new(81, 45, 19),
-                Map::new(68, 64, 13),
-            ]),
-            temperature_to_humidity_map: Converter::new(vec![
-                Map::new(0, 69, 1),
-                Map::new(1, 0, 69),
-            ]),
-            humidity_to_location_map: Converter::new(vec![
-                Map::new(60, 56, 37),
-                Map::new(56, 93, 4),
-            ]),
-        };
+        static ref TEST_ALMANAC: Almanac = Almanac::new(
+            vec![79, 14, 55, 13],
+            vec![
+                Stage::new(
+                    "seed",
+                    "soil",
+                    Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
+                ),
+                Stage::new(
+                    "soil",
+                    "fertilizer",
+                    Converter::new(vec![
+                        Map::new(0, 15, 37),
+                        Map::new(37, 52, 2),
+                        Map::new(39, 0, 15),
+                    ]),
+                ),
+                Stage::new(
+                    "fertilizer",
+                    "water",
+                    Converter::new(vec![
+                        Map::new(49, 53, 8),
+                        Map::new(0, 11, 42),
+                        Map::new(42, 0, 7),
+                        Map::new(57, 7, 4),
+                    ]),
+                ),
+                Stage::new(
+                    "water",
+                    "light",
+                    Converter::new(vec![Map::new(88, 18, 7), Map::new(18, 25, 70)]),
+                ),
+                Stage::new(
+                    "light",
+                    "temperature",
+                    Converter::new(vec![
+                        Map::new(45, 77, 23),
+                        Map::new(81, 45, 19),
+                        Map::new(68, 64, 13),
+                    ]),
+                ),
+                Stage::new(
+                    "temperature",
+                    "humidity",
+                    Converter::new(vec![Map::new(0, 69, 1), Map::new(1, 0, 69)]),
+                ),
+                Stage::new(
+                    "humidity",
+                    "location",
+                    Converter::new(vec![Map::new(60, 56, 37), Map::new(56, 93, 4)]),
+                ),
+            ],
+        );
+    }
+
+    fn identity(source: &str, destination: &str) -> Stage {
+        Stage::new(source, destination, Converter::new(vec![]))
     }
 
     #[test]
     fn test_calculate_seed_locations() {
         assert_eq!(
             TEST_ALMANAC.calculate_seed_locations(),
-            vec![82, 43, 86, 35]
+            Ok(vec![82, 43, 86, 35])
+        );
+    }
+
+    #[test]
+    fn test_calculate_location_ranges_with_seed_ranges() {
+        let location_ranges = TEST_ALMANAC
+            .calculate_location_ranges_with_seed_ranges()
+            .unwrap();
+
+        assert_eq!(location_ranges.first().map(|range| range.start), Some(46));
+    }
+
+    #[test]
+    fn test_location_ranges_match_seed_locations() {
+        let mut expected = TEST_ALMANAC
+            .calculate_seed_locations_with_seed_ranges()
+            .unwrap();
+        expected.sort();
+        expected.dedup();
+
+        let actual = TEST_ALMANAC
+            .calculate_location_ranges_with_seed_ranges()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_unpaired_seeds() {
+        let almanac = Almanac::new(vec![79, 14, 55], vec![identity("seed", "location")]);
+
+        assert_eq!(
+            almanac.calculate_location_ranges_with_seed_ranges(),
+            Err(ChainError::UnpairedSeeds(3))
+        );
+        assert_eq!(
+            almanac.calculate_seed_locations_with_seed_ranges(),
+            Err(ChainError::UnpairedSeeds(3))
+        );
+        let ranges = Almanac::new(vec![usize::MAX - 1, 5], vec![])
+            .seed_ranges()
+            .unwrap();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0], usize::MAX - 1..usize::MAX);
+    }
+
+    #[test]
+    fn test_convert_between_any_categories() {
+        // Seed 79 is soil 81, fertilizer 81, water 81, light 74, temperature 78 and humidity 78
+        assert_eq!(TEST_ALMANAC.convert("soil", "light", 81), Ok(74));
+        assert_eq!(TEST_ALMANAC.convert("light", "humidity", 74), Ok(78));
+        assert_eq!(TEST_ALMANAC.convert("seed", "location", 79), Ok(82));
+        assert_eq!(TEST_ALMANAC.convert("water", "water", 5), Ok(5));
+    }
+
+    #[test]
+    fn test_compose_matches_converting_stage_by_stage() {
+        let chain = TEST_ALMANAC.chain("seed", "location").unwrap();
+        let converter = TEST_ALMANAC.compose("seed", "location").unwrap();
+
+        for seed in 0..120 {
+            assert_eq!(converter.lookup(seed), convert_through(&chain, seed));
+        }
+    }
+
+    #[test]
+    fn test_compose_unknown_category() {
+        assert_eq!(
+            TEST_ALMANAC.compose("seed", "sunlight"),
+            Err(ChainError::UnknownCategory("sunlight".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_invert() {
+        let inverted = TEST_ALMANAC.invert("seed", "location").unwrap();
+
+        assert_eq!(
+            [82, 43, 86, 35].map(|location| inverted.lookup(location)),
+            [79, 14, 55, 13]
+        );
+    }
+
+    #[test]
+    fn test_preimage_of_a_location() {
+        let seeds = TEST_ALMANAC.preimage("seed", "location", 46..47).unwrap();
+
+        assert_eq!(seeds.len(), 1);
+        assert_eq!(seeds[0], 82..83);
+    }
+
+    #[test]
+    fn test_preimage_matches_convert() {
+        for location in 0..120 {
+            let expected = (0..200)
+                .filter(|seed| TEST_ALMANAC.convert("seed", "location", *seed) == Ok(location))
+                .collect::<Vec<_>>();
+            let actual = TEST_ALMANAC
+                .preimage("seed", "location", location..location + 1)
+                .unwrap()
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected, "location {}", location);
+        }
+    }
+
+    #[test]
+    fn test_convert_range_between_categories() {
+        assert_eq!(
+            TEST_ALMANAC.convert_range("seed", "soil", 96..100),
+            Ok(vec![50..52, 98..100])
+        );
+    }
+
+    #[test]
+    fn test_convert_against_the_chain_is_broken() {
+        assert_eq!(
+            TEST_ALMANAC.convert("location", "seed", 82),
+            Err(ChainError::Broken {
+                from: "location".to_string(),
+                to: "seed".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_convert_unknown_category() {
+        assert_eq!(
+            TEST_ALMANAC.convert("seed", "sunlight", 1),
+            Err(ChainError::UnknownCategory("sunlight".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_stages_in_any_order() {
+        let almanac = Almanac::new(
+            vec![1],
+            vec![
+                Stage::new("soil", "location", Converter::new(vec![Map::new(10, 0, 5)])),
+                Stage::new("seed", "soil", Converter::new(vec![Map::new(0, 1, 1)])),
+            ],
+        );
+
+        assert_eq!(almanac.calculate_seed_locations(), Ok(vec![10]));
+    }
+
+    #[test]
+    fn test_missing_stage_breaks_the_chain() {
+        let almanac = Almanac::new(
+            vec![1],
+            vec![identity("seed", "soil"), identity("water", "location")],
+        );
+
+        assert_eq!(
+            almanac.calculate_seed_locations(),
+            Err(ChainError::Broken {
+                from: "seed".to_string(),
+                to: "location".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_two_routes_are_ambiguous() {
+        let almanac = Almanac::new(
+            vec![1],
+            vec![
+                identity("seed", "soil"),
+                identity("soil", "location"),
+                identity("seed", "water"),
+                identity("water", "location"),
+            ],
+        );
+
+        assert_eq!(
+            almanac.calculate_seed_locations(),
+            Err(ChainError::Ambiguous {
+                from: "seed".to_string(),
+                to: "location".to_string(),
+            })
+        );
+        // Only the part of the graph that is needed has to be unambiguous
+        assert_eq!(almanac.convert("soil", "location", 1), Ok(1));
+    }
+
+    #[test]
+    fn test_cycles_are_not_followed() {
+        let almanac = Almanac::new(
+            vec![1],
+            vec![
+                identity("seed", "soil"),
+                identity("soil", "seed"),
+                identity("soil", "location"),
+            ],
+        );
+
+        assert_eq!(almanac.calculate_seed_locations(), Ok(vec![1]));
+    }
+
+    #[test]
+    fn test_overflowing_map_on_the_chain() {
+        let almanac = Almanac::new(
+            vec![1, 2],
+            vec![
+                Stage::new(
+                    "seed",
+                    "soil",
+                    Converter::new(vec![Map::new(0, usize::MAX - 1, 5)]),
+                ),
+                Stage::new("soil", "location", Converter::new(vec![])),
+                Stage::new(
+                    "soil",
+                    "water",
+                    Converter::new(vec![Map::new(usize::MAX, 0, 5)]),
+                ),
+            ],
+        );
+        let error = ChainError::Overflow(Diagnostic {
+            section: "seed-to-soil".to_string(),
+            line: None,
+            problem: Problem::Overflow { map: 0 },
+        });
+
+        assert_eq!(almanac.calculate_seed_locations(), Err(error));
+        assert!(almanac
+            .calculate_location_ranges_with_seed_ranges()
+            .is_err());
+        assert!(almanac.compose("soil", "water").is_err());
+        assert_eq!(almanac.convert("soil", "location", 7), Ok(7));
+    }
+
+    #[test]
+    fn test_overflow_reports_its_line() {
+        let input = indoc! {"
+            seeds: 1 2
+
+            seed-to-location map:
+            0 1 2
+            0 18446744073709551614 5
+        "};
+        let almanac = Almanac::from_string(input).unwrap();
+
+        assert_eq!(
+            almanac.calculate_seed_locations().unwrap_err().to_string(),
+            "line 5 (seed-to-location map): map 1 overflows"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(TEST_ALMANAC.validate().is_empty());
+
+        let almanac = Almanac::new(
+            vec![1],
+            vec![
+                Stage::new("seed", "soil", Converter::new(vec![Map::new(0, 0, 0)])).at_line(3),
+                Stage::new(
+                    "soil",
+                    "location",
+                    Converter::new(vec![Map::new(0, 5, 10), Map::new(20, 10, 5)]),
+                )
+                .at_line(6),
+            ],
+        );
+
+        assert_eq!(
+            almanac.validate(),
+            vec![
+                Diagnostic {
+                    section: "seed-to-soil".to_string(),
+                    line: Some(3),
+                    problem: Problem::EmptyStage,
+                },
+                Diagnostic {
+                    section: "soil-to-location".to_string(),
+                    line: Some(8),
+                    problem: Problem::OverlappingSources {
+                        first: 0,
+                        second: 1
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_parsed_almanac_reports_lines() {
+        let input = indoc! {"
+            seeds: 1 2
+
+            seed-to-soil map:
+            0 1 2
+
+            soil-to-location map:
+            10 0 5
+            12 20 5
+        "};
+        let almanac = Almanac::from_string(input).unwrap();
+        let diagnostics = almanac.validate();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "line 8 (soil-to-location map): map 1 overlaps the destination range of map 0"
+        );
+    }
+
+    #[test]
+    fn test_display_matches_puzzle_input() {
+        let file = std::fs::read_to_string("../test_input.txt").expect("Unable to read file");
+        let almanac = Almanac::from_string(&file).unwrap();
+
+        // The printed almanac always ends with a newline, the file doesn't
+        assert_eq!(almanac.to_string().trim_end(), file);
+    }
+
+    fn without_lines(mut almanac: Almanac) -> Almanac {
+        for stage in &mut almanac.stages {
+            stage.line = None;
+        }
+        almanac
+    }
+
+    fn arbitrary_almanac() -> impl Strategy<Value = Almanac> {
+        let map = (any::<usize>(), any::<usize>(), any::<usize>())
+            .prop_map(|(d, s, l)| Map::new(d, s, l));
+        let stage = ("[a-z][a-z0-9_]{0,8}", "[a-z][a-z0-9_]{0,8}", vec(map, 0..5)).prop_map(
+            |(source, destination, maps)| Stage::new(&source, &destination, Converter::new(maps)),
+        );
+        (vec(any::<usize>(), 0..6), vec(stage, 0..6))
+            .prop_map(|(seeds, stages)| Almanac::new(seeds, stages))
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trips(almanac in arbitrary_almanac()) {
+            let parsed = Almanac::from_string(&almanac.to_string()).unwrap();
+
+            prop_assert_eq!(without_lines(parsed), almanac);
+        }
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        assert_eq!(
+            merge_ranges(vec![10..20, 0..5, 5..7, 15..25, 30..30, 40..41]),
+            vec![0..7, 10..25, 40..41]
         );
     }
 }
@@ -1,3 +1,6 @@
+use std::fmt;
+use std::ops::Range;
+
 use crate::map::Map;
 
 #[derive(Debug, PartialEq)]
@@ -16,6 +19,185 @@ impl Converter {
         }
         value
     }
+
+    // Each map only sees the parts of the range that earlier maps left unconverted,
+    // matching the first-match behaviour of convert
+    pub fn convert_range(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut converted = Vec::new();
+        let mut unconverted = vec![range];
+
+        for map in &self.0 {
+            let mut remaining = Vec::new();
+            for range in unconverted {
+                let (converted_range, rest) = map.convert_range(range);
+                converted.extend(converted_range);
+                remaining.extend(rest);
+            }
+            unconverted = remaining;
+        }
+
+        // Anything that no map covers passes through unchanged
+        converted.extend(unconverted);
+        converted
+    }
+
+    pub fn maps(&self) -> &[Map] {
+        &self.0
+    }
+
+    // An equivalent converter whose maps are sorted by source and never overlap, with
+    // the parts of later maps shadowed by earlier ones already cut away
+    pub fn flatten(&self) -> Converter {
+        let mut maps = Vec::new();
+        for (index, map) in self.0.iter().enumerate() {
+            let mut uncovered = vec![map.source_range()];
+            for earlier in &self.0[..index] {
+                uncovered = uncovered
+                    .into_iter()
+                    .flat_map(|range| earlier.convert_range(range).1)
+                    .collect();
+            }
+            for range in uncovered {
+                let destination = map.convert(range.start).expect("Range is inside the map");
+                maps.push(Map::new(destination, range.start, range.len()));
+            }
+        }
+        maps.sort_by_key(|map| map.source_range().start);
+        Converter(join_adjacent(maps))
+    }
+
+    // A single converter equivalent to converting with self and then with next
+    pub fn compose(&self, next: &Converter) -> Converter {
+        let first = self.flatten();
+        let next = next.flatten();
+
+        let mut maps = Vec::new();
+        let mut cursor = 0;
+        for map in first.maps() {
+            let source = map.source_range();
+            // Values that self passes through unchanged only see next
+            maps.extend(next.split(cursor..source.start));
+
+            let destination = map.destination_range();
+            for piece in next.split(destination.clone()) {
+                let offset = piece.source_range().start - destination.start;
+                maps.push(Map::new(
+                    piece.destination_range().start,
+                    source.start + offset,
+                    piece.source_range().len(),
+                ));
+            }
+            cursor = source.end;
+        }
+        maps.extend(next.split(cursor..usize::MAX));
+
+        Converter(join_adjacent(maps))
+    }
+
+    // Only the true inverse when no two values convert to the same value, use preimage
+    // to find every value that converts into a range
+    pub fn invert(&self) -> Converter {
+        let mut maps = self.flatten().0.iter().map(Map::invert).collect::<Vec<_>>();
+        maps.sort_by_key(|map| map.source_range().start);
+        Converter(maps)
+    }
+
+    // Every value that converts to somewhere in the range
+    pub fn preimage(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let flattened = self.flatten();
+
+        let mut preimage = flattened
+            .0
+            .iter()
+            .filter_map(|map| map.invert().convert_range(range.clone()).0)
+            .collect::<Vec<_>>();
+
+        // Values that no map covers are converted to themselves
+        let mut unmapped = vec![range];
+        for map in &flattened.0 {
+            unmapped = unmapped
+                .into_iter()
+                .flat_map(|range| map.convert_range(range).1)
+                .collect();
+        }
+        preimage.extend(unmapped);
+        preimage
+    }
+
+    // Expects a flattened converter, finding the only map that could hold the value
+    // with a binary search instead of trying every map in turn
+    pub fn lookup(&self, value: usize) -> usize {
+        let index = self
+            .0
+            .partition_point(|map| map.source_range().start <= value);
+        match index
+            .checked_sub(1)
+            .and_then(|index| self.0[index].convert(value))
+        {
+            Some(converted_value) => converted_value,
+            None => value,
+        }
+    }
+
+    // Cuts a range into consecutive maps covering all of it, with identity maps for the
+    // gaps. Expects a flattened converter.
+    fn split(&self, range: Range<usize>) -> Vec<Map> {
+        let mut pieces = Vec::new();
+        let mut cursor = range.start;
+        for map in &self.0 {
+            let source = map.source_range();
+            let start = source.start.max(cursor);
+            let end = source.end.min(range.end);
+            if start >= end {
+                continue;
+            }
+            if cursor < start {
+                pieces.push(Map::new(cursor, cursor, start - cursor));
+            }
+            let destination = map.convert(start).expect("Start is inside the map");
+            pieces.push(Map::new(destination, start, end - start));
+            cursor = end;
+        }
+        if cursor < range.end {
+            pieces.push(Map::new(cursor, cursor, range.end - cursor));
+        }
+        pieces
+    }
+}
+
+// One map per line, in the order they are tried
+impl fmt::Display for Converter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, map) in self.0.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", map)?;
+        }
+        Ok(())
+    }
+}
+
+// Drops identity maps and joins maps that continue on from each other in both the
+// source and destination, expecting the maps to be sorted by source
+fn join_adjacent(maps: Vec<Map>) -> Vec<Map> {
+    let mut joined: Vec<Map> = Vec::with_capacity(maps.len());
+    for map in maps.into_iter().filter(|map| !map.is_identity()) {
+        match joined.last_mut() {
+            Some(last)
+                if last.source_range().end == map.source_range().start
+                    && last.destination_range().end == map.destination_range().start =>
+            {
+                *last = Map::new(
+                    last.destination_range().start,
+                    last.source_range().start,
+                    last.source_range().len() + map.source_range().len(),
+                );
+            }
+            _ => joined.push(map),
+        }
+    }
+    joined
 }
 
 #[cfg(test)]
@@ -56,4 +238,144 @@ mod test {
         assert_eq!(TEST_CONVERTER.convert(14), 14);
         assert_eq!(TEST_CONVERTER.convert(61), 61);
     }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(TEST_CONVERTER.to_string(), "50 98 2\n0 15 38\n49 53 8");
+    }
+
+    #[test]
+    fn test_convert_range_splits_at_map_boundaries() {
+        let mut actual = TEST_CONVERTER.convert_range(10..100);
+        actual.sort_by_key(|range| range.start);
+
+        // 10..15 and 61..98 are unmapped, 15..53 -> 0..38, 53..61 -> 49..57, 98..100 -> 50..52
+        assert_eq!(actual, vec![0..38, 10..15, 49..57, 50..52, 61..98]);
+    }
+
+    #[test]
+    fn test_convert_range_matches_convert() {
+        let converted = TEST_CONVERTER.convert_range(0..120);
+
+        let mut expected = (0..120)
+            .map(|value| TEST_CONVERTER.convert(value))
+            .collect::<Vec<_>>();
+        let mut actual = converted.into_iter().flatten().collect::<Vec<_>>();
+        expected.sort();
+        actual.sort();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_flatten_cuts_away_shadowed_maps() {
+        let converter = Converter::new(vec![
+            Map::new(100, 10, 10),
+            Map::new(200, 5, 20),
+            Map::new(30, 30, 5),
+        ]);
+
+        assert_eq!(
+            converter.flatten(),
+            Converter::new(vec![
+                Map::new(200, 5, 5),
+                Map::new(100, 10, 10),
+                Map::new(215, 20, 5),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_flatten_joins_adjacent_maps() {
+        let converter = Converter::new(vec![Map::new(14, 4, 6), Map::new(10, 0, 4)]);
+
+        assert_eq!(
+            converter.flatten(),
+            Converter::new(vec![Map::new(10, 0, 10)])
+        );
+    }
+
+    #[test]
+    fn test_compose_matches_converting_twice() {
+        let next = Converter::new(vec![
+            Map::new(0, 69, 1),
+            Map::new(1, 0, 69),
+            Map::new(500, 99, 3),
+        ]);
+        let composed = TEST_CONVERTER.compose(&next);
+
+        for value in 0..200 {
+            assert_eq!(
+                composed.lookup(value),
+                next.convert(TEST_CONVERTER.convert(value)),
+                "value {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_compose_is_sorted_and_non_overlapping() {
+        let next = Converter::new(vec![Map::new(10, 40, 30), Map::new(0, 0, 10)]);
+        let composed = TEST_CONVERTER.compose(&next);
+
+        for pair in composed.maps().windows(2) {
+            assert!(pair[0].source_range().end <= pair[1].source_range().start);
+        }
+    }
+
+    #[test]
+    fn test_compose_with_identity() {
+        let identity = Converter::new(vec![]);
+
+        assert_eq!(identity.compose(&TEST_CONVERTER), TEST_CONVERTER.flatten());
+        assert_eq!(TEST_CONVERTER.compose(&identity), TEST_CONVERTER.flatten());
+    }
+
+    #[test]
+    fn test_invert() {
+        let converter = Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]);
+        let inverted = converter.invert();
+
+        for value in 0..120 {
+            assert_eq!(inverted.lookup(converter.convert(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_preimage() {
+        let mut preimage = TEST_CONVERTER.preimage(50..52);
+        preimage.sort_by_key(|range| range.start);
+
+        // 98..100 -> 50..52 and 54..56 -> 50..52, while 50..52 itself is converted away
+        assert_eq!(preimage, vec![54..56, 98..100]);
+    }
+
+    #[test]
+    fn test_preimage_matches_convert() {
+        let converter = Converter::new(vec![Map::new(0, 10, 10), Map::new(5, 30, 5)]);
+
+        for value in 0..40 {
+            let expected = (0..40)
+                .filter(|source| converter.convert(*source) == value)
+                .collect::<Vec<_>>();
+            let mut actual = converter
+                .preimage(value..value + 1)
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            actual.sort();
+
+            assert_eq!(actual, expected, "value {}", value);
+        }
+    }
+
+    #[test]
+    fn test_lookup_matches_convert() {
+        let flattened = TEST_CONVERTER.flatten();
+
+        for value in 0..120 {
+            assert_eq!(flattened.lookup(value), TEST_CONVERTER.convert(value));
+        }
+    }
 }
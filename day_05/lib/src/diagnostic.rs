@@ -0,0 +1,48 @@
+use std::fmt;
+
+// Maps are numbered by their position within their section, starting from zero
+#[derive(Debug, PartialEq)]
+pub enum Problem {
+    OverlappingSources { first: usize, second: usize },
+    OverlappingDestinations { first: usize, second: usize },
+    Overflow { map: usize },
+    EmptyStage,
+}
+
+// Line numbers are only known for almanacs that were parsed
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    pub section: String,
+    pub line: Option<usize>,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::OverlappingSources { first, second } => {
+                write!(
+                    f,
+                    "map {} overlaps the source range of map {}",
+                    second, first
+                )
+            }
+            Problem::OverlappingDestinations { first, second } => write!(
+                f,
+                "map {} overlaps the destination range of map {}",
+                second, first
+            ),
+            Problem::Overflow { map } => write!(f, "map {} overflows", map),
+            Problem::EmptyStage => write!(f, "no map converts any values"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {} ({} map): {}", line, self.section, self.problem),
+            None => write!(f, "{} map: {}", self.section, self.problem),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::almanac::Almanac;
+use crate::converter::Converter;
+use crate::map::Map;
+use crate::stage::Stage;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+#[derive(Debug, Clone)]
+pub struct GeneratorConfig {
+    // Seeds are generated as start and length pairs
+    pub seed_ranges: usize,
+    pub max_seed_range_length: usize,
+    pub maps_per_stage: usize,
+    // Every seed, source and destination is below this
+    pub max_value: usize,
+    // Lets maps overlap other maps' sources and destinations, so the first match matters
+    pub allow_overlaps: bool,
+    pub seed: u64,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        Self {
+            seed_ranges: 10,
+            max_seed_range_length: 1_000,
+            maps_per_stage: 20,
+            max_value: 1_000_000,
+            allow_overlaps: false,
+            seed: 0,
+        }
+    }
+}
+
+// The same config always generates the same almanac
+pub fn generate(config: &GeneratorConfig) -> Almanac {
+    assert!(config.max_value > 0, "Max value must be positive");
+    assert!(
+        config.max_value <= usize::MAX / 2,
+        "Max value must leave room to convert without overflowing"
+    );
+    let mut rng = StdRng::seed_from_u64(config.seed);
+
+    let seeds = (0..config.seed_ranges)
+        .flat_map(|_| {
+            let start = rng.gen_range(0..config.max_value);
+            let length = rng.gen_range(1..=config.max_seed_range_length.max(1));
+            [start, length.min(config.max_value - start)]
+        })
+        .collect();
+
+    let stages = CATEGORIES
+        .windows(2)
+        .map(|pair| Stage::new(pair[0], pair[1], generate_converter(&mut rng, config)))
+        .collect();
+
+    Almanac::new(seeds, stages)
+}
+
+// Cuts 0..max_value into consecutive source ranges and shuffles where they end up,
+// so no two maps share a source or a destination unless overlaps are allowed
+fn generate_converter(rng: &mut StdRng, config: &GeneratorConfig) -> Converter {
+    let mut cuts = (0..config.maps_per_stage.saturating_sub(1))
+        .map(|_| rng.gen_range(0..=config.max_value))
+        .collect::<Vec<_>>();
+    cuts.extend([0, config.max_value]);
+    cuts.sort_unstable();
+
+    let sources = cuts
+        .windows(2)
+        .map(|pair| pair[0]..pair[1])
+        .collect::<Vec<_>>();
+
+    // Lay the sources out end to end in a shuffled order so each keeps its length
+    let mut order = (0..sources.len()).collect::<Vec<_>>();
+    order.shuffle(rng);
+    let mut destinations = vec![0..0; sources.len()];
+    let mut start = 0;
+    for index in order {
+        let length = sources[index].len();
+        destinations[index] = start..start + length;
+        start += length;
+    }
+
+    let mut maps = destinations.into_iter().zip(sources).collect::<Vec<_>>();
+    maps.shuffle(rng);
+
+    if config.allow_overlaps {
+        for (destination, source) in &mut maps {
+            if rng.gen_bool(0.25) {
+                let length = source.len();
+                let start = rng.gen_range(0..=config.max_value - length);
+                *source = start..start + length;
+            }
+            if rng.gen_bool(0.25) {
+                let length = destination.len();
+                let start = rng.gen_range(0..=config.max_value - length);
+                *destination = start..start + length;
+            }
+        }
+    }
+
+    Converter::new(
+        maps.into_iter()
+            .filter(|(_, source)| !source.is_empty())
+            .map(|(destination, source)| Map::new(destination.start, source.start, source.len()))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn small_config(seed: u64) -> GeneratorConfig {
+        GeneratorConfig {
+            seed_ranges: 3,
+            max_seed_range_length: 50,
+            maps_per_stage: 5,
+            max_value: 500,
+            allow_overlaps: false,
+            seed,
+        }
+    }
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        let config = GeneratorConfig::default();
+
+        assert_eq!(generate(&config), generate(&config));
+        assert_ne!(
+            generate(&config),
+            generate(&GeneratorConfig { seed: 1, ..config })
+        );
+    }
+
+    #[test]
+    fn test_generate_shape() {
+        let almanac = generate(&small_config(0));
+
+        assert_eq!(almanac.seeds.len(), 6);
+        assert_eq!(almanac.stages.len(), 7);
+        assert!(almanac.seeds.iter().all(|seed| *seed < 500));
+        assert!(almanac
+            .stages
+            .iter()
+            .all(|stage| stage.converter.maps().len() <= 5));
+    }
+
+    #[test]
+    fn test_generate_without_overlaps_is_valid() {
+        for seed in 0..20 {
+            assert!(generate(&small_config(seed)).validate().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_generate_with_overlaps() {
+        let overlapping = (0..20)
+            .map(|seed| {
+                generate(&GeneratorConfig {
+                    allow_overlaps: true,
+                    ..small_config(seed)
+                })
+            })
+            .filter(|almanac| !almanac.validate().is_empty())
+            .count();
+
+        assert!(overlapping > 0);
+    }
+
+    #[test]
+    fn test_generated_almanacs_parse() {
+        for seed in 0..20 {
+            let almanac = generate(&GeneratorConfig {
+                allow_overlaps: seed % 2 == 0,
+                ..small_config(seed)
+            });
+            let printed = almanac.to_string();
+
+            assert_eq!(Almanac::from_string(&printed).unwrap().to_string(), printed);
+        }
+    }
+
+    #[test]
+    fn test_seed_ranges_match_brute_force() {
+        for seed in 0..10 {
+            let almanac = generate(&GeneratorConfig {
+                allow_overlaps: true,
+                ..small_config(seed)
+            });
+            let mut expected = almanac.calculate_seed_locations_with_seed_ranges().unwrap();
+            expected.sort();
+            expected.dedup();
+
+            let actual = almanac
+                .calculate_location_ranges_with_seed_ranges()
+                .unwrap()
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected);
+        }
+    }
+}
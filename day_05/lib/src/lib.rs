@@ -1,4 +1,7 @@
 pub mod almanac;
 pub mod converter;
+pub mod diagnostic;
+pub mod generator;
 pub mod map;
 pub mod parser;
+pub mod stage;
@@ -1,4 +1,7 @@
-#[derive(Debug, PartialEq)]
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Map {
     destination_range_start: usize,
     source_range_start: usize,
@@ -18,18 +21,93 @@ impl Map {
         }
     }
 
+    // Ranges of maps that overflow are cut short at usize::MAX
+    pub fn source_range(&self) -> Range<usize> {
+        self.source_range_start..self.source_range_start.saturating_add(self.range_length)
+    }
+
+    pub fn destination_range(&self) -> Range<usize> {
+        self.destination_range_start
+            ..self
+                .destination_range_start
+                .saturating_add(self.range_length)
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.destination_range_start == self.source_range_start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range_length == 0
+    }
+
+    // Either end of the map lying past usize::MAX means its ranges can't be computed
+    pub fn overflows(&self) -> bool {
+        self.source_range_start
+            .checked_add(self.range_length)
+            .and(self.destination_range_start.checked_add(self.range_length))
+            .is_none()
+    }
+
+    // Avoids computing the end of the source range so hostile maps can't overflow
     fn is_in_range(&self, value: usize) -> bool {
-        (self.source_range_start..self.source_range_start + self.range_length).contains(&value)
+        value >= self.source_range_start && value - self.source_range_start < self.range_length
     }
 
     pub fn convert(&self, value: usize) -> Option<usize> {
         if self.is_in_range(value) {
             let offset = value - self.source_range_start;
-            Some(self.destination_range_start + offset)
+            self.destination_range_start.checked_add(offset)
         } else {
             None
         }
     }
+
+    pub fn invert(&self) -> Map {
+        Map::new(
+            self.source_range_start,
+            self.destination_range_start,
+            self.range_length,
+        )
+    }
+
+    // Splits a range into the part this map converts and the parts either side of it that it doesn't
+    pub fn convert_range(&self, range: Range<usize>) -> (Option<Range<usize>>, Vec<Range<usize>>) {
+        if range.is_empty() {
+            return (None, Vec::new());
+        }
+
+        let source = self.source_range();
+        let start = range.start.max(source.start);
+        let end = range.end.min(source.end);
+        if start >= end {
+            return (None, vec![range]);
+        }
+
+        let mut unconverted = Vec::new();
+        if range.start < start {
+            unconverted.push(range.start..start);
+        }
+        if end < range.end {
+            unconverted.push(end..range.end);
+        }
+
+        let offset = start - source.start;
+        let converted_start = self.destination_range_start.saturating_add(offset);
+        let converted = converted_start..converted_start.saturating_add(end - start);
+
+        (Some(converted), unconverted)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_range_start, self.source_range_start, self.range_length
+        )
+    }
 }
 
 #[cfg(test)]
@@ -48,6 +126,31 @@ mod test {
         assert!(!map.is_in_range(100));
     }
 
+    #[test]
+    fn test_is_in_range_near_usize_max() {
+        let map = Map::new(0, usize::MAX - 1, 5);
+
+        assert!(map.is_in_range(usize::MAX));
+        assert!(!map.is_in_range(0));
+        assert!(map.overflows());
+        assert!(!Map::new(0, usize::MAX - 5, 5).overflows());
+    }
+
+    #[test]
+    fn test_overflowing_maps_do_not_panic() {
+        let map = Map::new(usize::MAX - 1, 0, 5);
+
+        assert_eq!(map.source_range(), 0..5);
+        assert_eq!(map.destination_range(), usize::MAX - 1..usize::MAX);
+        assert_eq!(map.convert(1), Some(usize::MAX));
+        assert_eq!(map.convert(2), None);
+        assert_eq!(map.convert_range(0..5).0, Some(usize::MAX - 1..usize::MAX));
+        assert_eq!(
+            Map::new(0, usize::MAX - 1, 5).source_range(),
+            usize::MAX - 1..usize::MAX
+        );
+    }
+
     #[test]
     fn test_convert() {
         let map = Map {
@@ -59,4 +162,64 @@ mod test {
         assert_eq!(map.convert(99), Some(51));
         assert_eq!(map.convert(100), None);
     }
+
+    #[test]
+    fn test_invert() {
+        let map = Map::new(50, 98, 2);
+        let inverted = map.invert();
+
+        assert_eq!(inverted, Map::new(98, 50, 2));
+        assert_eq!(inverted.convert(51), Some(99));
+        assert_eq!(inverted.invert(), map);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Map::new(50, 98, 2).to_string(), "50 98 2");
+    }
+
+    #[test]
+    fn test_convert_range_inside() {
+        let map = Map::new(52, 50, 48);
+        let (converted, unconverted) = map.convert_range(79..93);
+        assert_eq!(converted, Some(81..95));
+        assert!(unconverted.is_empty());
+    }
+
+    #[test]
+    fn test_convert_range_overlapping_both_ends() {
+        let map = Map::new(50, 98, 2);
+        let (converted, unconverted) = map.convert_range(90..110);
+        assert_eq!(converted, Some(50..52));
+        assert_eq!(unconverted, [90..98, 100..110]);
+    }
+
+    #[test]
+    fn test_convert_range_partial_overlap() {
+        let map = Map::new(50, 98, 2);
+
+        let (converted, unconverted) = map.convert_range(99..105);
+        assert_eq!(converted, Some(51..52));
+        assert_eq!(unconverted.len(), 1);
+        assert_eq!(unconverted[0], 100..105);
+
+        let (converted, unconverted) = map.convert_range(95..99);
+        assert_eq!(converted, Some(50..51));
+        assert_eq!(unconverted.len(), 1);
+        assert_eq!(unconverted[0], 95..98);
+    }
+
+    #[test]
+    fn test_convert_range_outside() {
+        let map = Map::new(50, 98, 2);
+
+        let (converted, unconverted) = map.convert_range(0..98);
+        assert_eq!(converted, None);
+        assert_eq!(unconverted.len(), 1);
+        assert_eq!(unconverted[0], 0..98);
+
+        let (converted, unconverted) = map.convert_range(5..5);
+        assert_eq!(converted, None);
+        assert!(unconverted.is_empty());
+    }
 }
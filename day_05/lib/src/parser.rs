@@ -1,93 +1,192 @@
 use std::error::Error;
+use std::fmt;
 
 use nom::{
-    bytes::complete::tag,
-    character::complete::{digit1, line_ending, space1},
-    combinator::{map, map_res, opt},
-    multi::many1,
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::{digit1, line_ending, multispace0, space1},
+    combinator::{cut, eof, map, map_res, opt, peek, recognize, rest_len},
+    error::{context, VerboseError, VerboseErrorKind},
+    multi::{many0, many_till},
     sequence::{preceded, separated_pair, terminated, tuple},
-    IResult,
+    IResult, Offset,
 };
 
 use crate::almanac::Almanac;
 use crate::converter::Converter;
 use crate::map::Map;
+use crate::stage::Stage;
 
-fn parse_integer(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, str::parse)(input)
+type ParseResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+// Owns everything it needs so it can outlive the input it was parsed from
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub snippet: String,
+}
+
+impl ParseError {
+    fn new(input: &str, error: VerboseError<&str>) -> Self {
+        // The first error is where parsing actually stopped, the contexts wrapping it
+        // follow from the innermost outwards
+        let remaining = error.errors.first().map_or("", |(remaining, _)| *remaining);
+        let expected = error
+            .errors
+            .iter()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(context.to_string()),
+                _ => None,
+            })
+            .or_else(|| {
+                error.errors.first().map(|(_, kind)| match kind {
+                    VerboseErrorKind::Char(c) => format!("`{}`", c),
+                    VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+                    VerboseErrorKind::Context(context) => context.to_string(),
+                })
+            })
+            .unwrap_or_default();
+
+        let offset = input.offset(remaining);
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+        let column = input[line_start..offset].chars().count() + 1;
+
+        Self {
+            line: line_number(input, offset),
+            column,
+            expected,
+            snippet: format!(
+                "{}\n{}^",
+                input[line_start..line_end].trim_end_matches('\r'),
+                " ".repeat(column - 1)
+            ),
+        }
+    }
 }
 
-fn parse_seeds(input: &str) -> IResult<&str, Vec<usize>> {
-    preceded(tag("seeds:"), many1(preceded(space1, parse_integer)))(input)
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        write!(f, "{}", self.snippet)
+    }
 }
 
-fn parse_map(input: &str) -> IResult<&str, Map> {
+impl Error for ParseError {}
+
+// Digits that don't fit are reported where the number starts rather than against whatever
+// was being parsed when they were reached
+fn parse_integer(input: &str) -> ParseResult<'_, usize> {
+    preceded(
+        peek(digit1),
+        cut(context(
+            "number that fits in a usize",
+            map_res(digit1, str::parse),
+        )),
+    )(input)
+}
+
+// An almanac may list no seeds at all, but the line must end after the last one
+fn parse_seeds(input: &str) -> ParseResult<'_, Vec<usize>> {
+    context(
+        "`seeds:` line",
+        terminated(
+            preceded(tag("seeds:"), many0(preceded(space1, parse_integer))),
+            peek(alt((line_ending, eof))),
+        ),
+    )(input)
+}
+
+fn parse_map(input: &str) -> ParseResult<'_, Map> {
     let parser = tuple((
         parse_integer,
         preceded(space1, parse_integer),
         preceded(space1, parse_integer),
     ));
-    map(
-        parser,
-        |(destination_range_start, source_range_start, range_length)| {
-            Map::new(destination_range_start, source_range_start, range_length)
-        },
+    context(
+        "map of three numbers",
+        map(
+            parser,
+            |(destination_range_start, source_range_start, range_length)| {
+                Map::new(destination_range_start, source_range_start, range_length)
+            },
+        ),
     )(input)
 }
 
-fn parse_converter<'a>(input: &'a str, section_name: &str) -> IResult<&'a str, Converter> {
+fn parse_category(input: &str) -> ParseResult<'_, &str> {
+    take_while1(|c: char| c.is_alphanumeric() || c == '_')(input)
+}
+
+// Section headers look like `seed-to-soil map:`
+fn parse_section_header(input: &str) -> ParseResult<'_, (&str, &str)> {
+    context(
+        "`source-to-destination map:` header",
+        terminated(
+            separated_pair(parse_category, tag("-to-"), parse_category),
+            tag(" map:"),
+        ),
+    )(input)
+}
+
+fn parse_stage(input: &str) -> ParseResult<'_, Stage> {
     let section_header = preceded(
         opt(line_ending),
-        separated_pair(tag(section_name), tag(":"), line_ending),
-    );
-    let parser = preceded(
-        section_header,
-        many1(terminated(parse_map, opt(line_ending))),
+        terminated(parse_section_header, line_ending),
     );
-    map(preceded(opt(line_ending), parser), Converter::new)(input)
-}
-
-pub fn parse_almanac(input: &str) -> Result<Almanac, Box<dyn Error + '_>> {
-    let parser = tuple((
-        parse_seeds,
-        |i| parse_converter(i, "seed-to-soil map"),
-        |i| parse_converter(i, "soil-to-fertilizer map"),
-        |i| parse_converter(i, "fertilizer-to-water map"),
-        |i| parse_converter(i, "water-to-light map"),
-        |i| parse_converter(i, "light-to-temperature map"),
-        |i| parse_converter(i, "temperature-to-humidity map"),
-        |i| parse_converter(i, "humidity-to-location map"),
+    // A section can be empty. Once the header has been read anything other than a map
+    // before the end of the section is reported against the map rather than as a missing
+    // header. Trying to parse a map last makes its error the one that is reported.
+    let end_of_section = peek(alt((
+        line_ending,
+        eof,
+        recognize(parse_section_header),
+        recognize(parse_map),
+    )));
+    let maps = cut(terminated(
+        many0(terminated(parse_map, opt(line_ending))),
+        end_of_section,
     ));
-    match map(
-        parser,
-        |(
-            seeds,
-            seed_to_soil_map,
-            soil_to_fertilizer_map,
-            fertilizer_to_water_map,
-            water_to_light_map,
-            light_to_temperature_map,
-            temperature_to_humidity_map,
-            humidity_to_location_map,
-        )| {
-            Almanac {
-                seeds,
-                seed_to_soil_map,
-                soil_to_fertilizer_map,
-                fertilizer_to_water_map,
-                water_to_light_map,
-                light_to_temperature_map,
-                temperature_to_humidity_map,
-                humidity_to_location_map,
-            }
-        },
+    map(
+        preceded(opt(line_ending), tuple((section_header, maps))),
+        |((source, destination), maps)| Stage::new(source, destination, Converter::new(maps)),
     )(input)
-    {
+}
+
+pub fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    // The length of what is left to parse at each header gives away the header's line
+    let stage = |remaining| {
+        map(
+            preceded(many0(line_ending), tuple((rest_len, parse_stage))),
+            |(remaining, stage)| stage.at_line(line_number(input, input.len() - remaining)),
+        )(remaining)
+    };
+    // Trying for the end of the input before each stage means a broken stage reports its
+    // own error rather than unexpected trailing input
+    let stages = map(
+        many_till(stage, preceded(multispace0, eof)),
+        |(stages, _)| stages,
+    );
+    let parser = tuple((parse_seeds, stages));
+    match map(parser, |(seeds, stages)| Almanac::new(seeds, stages))(input) {
         Ok((_, almanac)) => Ok(almanac),
-        Err(e) => Err(Box::new(e)),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => Err(ParseError::new(input, e)),
+        Err(nom::Err::Incomplete(_)) => unreachable!("Only complete parsers are used"),
     }
 }
 
+fn line_number(input: &str, offset: usize) -> usize {
+    input[..offset].matches('\n').count() + 1
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +240,38 @@ mod tests {
     #[test]
     fn test_parse_seeds_error() {
         assert!(parse_seeds("seeds: abc").is_err());
+        assert!(parse_seeds("seeds: 1 x").is_err());
+    }
+
+    #[test]
+    fn test_parse_no_seeds() {
+        assert_eq!(parse_seeds("seeds:\n"), Ok(("\n", vec![])));
+    }
+
+    #[test]
+    fn test_parse_almanac_with_empty_sections() {
+        let test_input = indoc! {"
+            seeds:
+
+            seed-to-soil map:
+
+            soil-to-location map:
+            0 1 2
+        "};
+
+        let expected = Almanac::new(
+            vec![],
+            vec![
+                Stage::new("seed", "soil", Converter::new(vec![])).at_line(3),
+                Stage::new("soil", "location", Converter::new(vec![Map::new(0, 1, 2)])).at_line(5),
+            ],
+        );
+
+        assert_eq!(parse_almanac(test_input).unwrap(), expected);
+        assert_eq!(
+            parse_almanac("seeds: 1\n").unwrap(),
+            Almanac::new(vec![1], vec![])
+        );
     }
 
     #[test]
@@ -151,7 +282,11 @@ mod tests {
 
     #[test]
     fn test_parse_integer_error() {
-        assert!(parse_integer("abc").is_err());
+        assert!(matches!(parse_integer("abc"), Err(nom::Err::Error(_))));
+        assert!(matches!(
+            parse_integer("99999999999999999999999"),
+            Err(nom::Err::Failure(_))
+        ));
     }
 
     #[test]
@@ -167,99 +302,302 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_converter() {
+    fn test_parse_section_header() {
+        assert_eq!(
+            parse_section_header("seed-to-soil map:"),
+            Ok(("", ("seed", "soil")))
+        );
+        assert_eq!(
+            parse_section_header("humidity-to-location map:\n"),
+            Ok(("\n", ("humidity", "location")))
+        );
+    }
+
+    #[test]
+    fn test_parse_section_header_error() {
+        assert!(parse_section_header("seed-soil map:").is_err());
+        assert!(parse_section_header("seed-to-soil:").is_err());
+        assert!(parse_section_header("-to-soil map:").is_err());
+    }
+
+    #[test]
+    fn test_parse_stage() {
         let test_input = indoc! {"
             seed-to-soil map:
             50 98 2
             52 50 48
             "};
 
-        let expected = Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]);
-
-        assert_eq!(
-            parse_converter(test_input, "seed-to-soil map"),
-            Ok(("", expected))
+        let expected = Stage::new(
+            "seed",
+            "soil",
+            Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
         );
+
+        assert_eq!(parse_stage(test_input), Ok(("", expected)));
     }
 
     #[test]
-    fn test_parse_converter_error() {
+    fn test_parse_stage_error() {
         let test_input = indoc! {"
-            seed-to-soil map:
+            seed-to-soil map
             50 98 2
             52 50 48
             "};
 
-        assert!(parse_converter(test_input, "abc").is_err());
+        assert!(parse_stage(test_input).is_err());
     }
 
     #[test]
-    fn test_parse_almanac() {
-        let expected = Almanac {
-            seeds: vec![79, 14, 55, 13],
-            seed_to_soil_map: Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
-            soil_to_fertilizer_map: Converter::new(vec![
-                Map::new(0, 15, 37),
-                Map::new(37, 52, 2),
-                Map::new(39, 0, 15),
-            ]),
-            fertilizer_to_water_map: Converter::new(vec![
-                Map::new(49, 53, 8),
-                Map::new(0, 11, 42),
-                Map::new(42, 0, 7),
-                Map::new(57, 7, 4),
-            ]),
-            water_to_light_map: Converter::new(vec![Map::new(88, 18, 7), Map::new(18, 25, 70)]),
-            light_to_temperature_map: Converter::new(vec![
-                Map::new(45, 77, 23),
-                Map::new(81, 45, 19),
-                Map::new(68, 64, 13),
-            ]),
-            temperature_to_humidity_map: Converter::new(vec![
-                Map::new(0, 69, 1),
-                Map::new(1, 0, 69),
-            ]),
-            humidity_to_location_map: Converter::new(vec![
-                Map::new(60, 56, 37),
-                Map::new(56, 93, 4),
-            ]),
+    fn test_parse_almanac_with_any_stages() {
+        let test_input = indoc! {"
+            seeds: 1 2
+
+            light-to-location map:
+            7 0 3
+
+            seed-to-light map:
+            0 1 2
+        "};
+
+        let expected = Almanac::new(
+            vec![1, 2],
+            vec![
+                Stage::new("light", "location", Converter::new(vec![Map::new(7, 0, 3)])).at_line(3),
+                Stage::new("seed", "light", Converter::new(vec![Map::new(0, 1, 2)])).at_line(6),
+            ],
+        );
+
+        assert_eq!(parse_almanac(test_input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_almanac_rejects_trailing_garbage() {
+        let test_input = indoc! {"
+            seeds: 1 2
+
+            seed-to-soil map:
+            0 1 2
+
+            soil-to-location map
+            0 1 2
+        "};
+
+        assert!(parse_almanac(test_input).is_err());
+    }
+
+    #[test]
+    fn test_parse_almanac_reports_bad_header() {
+        let test_input = indoc! {"
+            seeds: 1 2
+
+            seed-to-soil map:
+            0 1 2
+
+            soil-location map:
+            0 1 2
+        "};
+
+        let error = parse_almanac(test_input).unwrap_err();
+
+        assert_eq!(error.line, 6);
+        assert_eq!(error.column, 5);
+        assert_eq!(error.expected, "`source-to-destination map:` header");
+        assert_eq!(
+            error.to_string(),
+            indoc! {"
+                line 6, column 5: expected `source-to-destination map:` header
+                soil-location map:
+                    ^"}
+        );
+    }
+
+    #[test]
+    fn test_parse_almanac_reports_bad_map() {
+        let test_input = indoc! {"
+            seeds: 1 2
+
+            seed-to-soil map:
+            0 1 2
+            3 x 5
+        "};
+
+        let error = parse_almanac(test_input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (5, 3));
+        assert_eq!(error.expected, "map of three numbers");
+        assert_eq!(error.snippet, "3 x 5\n  ^");
+    }
+
+    #[test]
+    fn test_parse_almanac_reports_number_too_large() {
+        let test_input = indoc! {"
+            seeds: 1 2
+
+            seed-to-soil map:
+            0 1 2
+            3 99999999999999999999999 5
+        "};
+
+        let error = parse_almanac(test_input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (5, 3));
+        assert_eq!(error.expected, "number that fits in a usize");
+
+        let error = parse_almanac("seeds: 1 99999999999999999999999\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 10));
+        assert_eq!(error.expected, "number that fits in a usize");
+    }
+
+    #[test]
+    fn test_parse_almanac_reports_bad_seeds() {
+        let error = parse_almanac("seeds 1 2\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "`seeds:` line");
+    }
+
+    #[test]
+    fn test_parse_error_outlives_input() {
+        let error = {
+            let input = String::from("seeds: 1\n\nseed-to-soil map:\n1 2\n");
+            parse_almanac(&input).unwrap_err()
         };
 
+        assert_eq!(error.line, 4);
+        assert_eq!(error.expected, "map of three numbers");
+    }
+
+    #[test]
+    fn test_parse_almanac() {
+        let expected = Almanac::new(
+            vec![79, 14, 55, 13],
+            vec![
+                Stage::new(
+                    "seed",
+                    "soil",
+                    Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
+                )
+                .at_line(3),
+                Stage::new(
+                    "soil",
+                    "fertilizer",
+                    Converter::new(vec![
+                        Map::new(0, 15, 37),
+                        Map::new(37, 52, 2),
+                        Map::new(39, 0, 15),
+                    ]),
+                )
+                .at_line(7),
+                Stage::new(
+                    "fertilizer",
+                    "water",
+                    Converter::new(vec![
+                        Map::new(49, 53, 8),
+                        Map::new(0, 11, 42),
+                        Map::new(42, 0, 7),
+                        Map::new(57, 7, 4),
+                    ]),
+                )
+                .at_line(12),
+                Stage::new(
+                    "water",
+                    "light",
+                    Converter::new(vec![Map::new(88, 18, 7), Map::new(18, 25, 70)]),
+                )
+                .at_line(18),
+                Stage::new(
+                    "light",
+                    "temperature",
+                    Converter::new(vec![
+                        Map::new(45, 77, 23),
+                        Map::new(81, 45, 19),
+                        Map::new(68, 64, 13),
+                    ]),
+                )
+                .at_line(22),
+                Stage::new(
+                    "temperature",
+                    "humidity",
+                    Converter::new(vec![Map::new(0, 69, 1), Map::new(1, 0, 69)]),
+                )
+                .at_line(27),
+                Stage::new(
+                    "humidity",
+                    "location",
+                    Converter::new(vec![Map::new(60, 56, 37), Map::new(56, 93, 4)]),
+                )
+                .at_line(31),
+            ],
+        );
+
         let actual = parse_almanac(TEST_INPUT).unwrap();
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn test_parse_almanac_from_file() {
-        let expected = Almanac {
-            seeds: vec![79, 14, 55, 13],
-            seed_to_soil_map: Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
-            soil_to_fertilizer_map: Converter::new(vec![
-                Map::new(0, 15, 37),
-                Map::new(37, 52, 2),
-                Map::new(39, 0, 15),
-            ]),
-            fertilizer_to_water_map: Converter::new(vec![
-                Map::new(49, 53, 8),
-                Map::new(0, 11, 42),
-                Map::new(42, 0, 7),
-                Map::new(57, 7, 4),
-            ]),
-            water_to_light_map: Converter::new(vec![Map::new(88, 18, 7), Map::new(18, 25, 70)]),
-            light_to_temperature_map: Converter::new(vec![
-                Map::new(45, 77, 23),
-                Map::new(81, 45, 19),
-                Map::new(68, 64, 13),
-            ]),
-            temperature_to_humidity_map: Converter::new(vec![
-                Map::new(0, 69, 1),
-                Map::new(1, 0, 69),
-            ]),
-            humidity_to_location_map: Converter::new(vec![
-                Map::new(60, 56, 37),
-                Map::new(56, 93, 4),
-            ]),
-        };
+        let expected = Almanac::new(
+            vec![79, 14, 55, 13],
+            vec![
+                Stage::new(
+                    "seed",
+                    "soil",
+                    Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
+                )
+                .at_line(3),
+                Stage::new(
+                    "soil",
+                    "fertilizer",
+                    Converter::new(vec![
+                        Map::new(0, 15, 37),
+                        Map::new(37, 52, 2),
+                        Map::new(39, 0, 15),
+                    ]),
+                )
+                .at_line(7),
+                Stage::new(
+                    "fertilizer",
+                    "water",
+                    Converter::new(vec![
+                        Map::new(49, 53, 8),
+                        Map::new(0, 11, 42),
+                        Map::new(42, 0, 7),
+                        Map::new(57, 7, 4),
+                    ]),
+                )
+                .at_line(12),
+                Stage::new(
+                    "water",
+                    "light",
+                    Converter::new(vec![Map::new(88, 18, 7), Map::new(18, 25, 70)]),
+                )
+                .at_line(18),
+                Stage::new(
+                    "light",
+                    "temperature",
+                    Converter::new(vec![
+                        Map::new(45, 77, 23),
+                        Map::new(81, 45, 19),
+                        Map::new(68, 64, 13),
+                    ]),
+                )
+                .at_line(22),
+                Stage::new(
+                    "temperature",
+                    "humidity",
+                    Converter::new(vec![Map::new(0, 69, 1), Map::new(1, 0, 69)]),
+                )
+                .at_line(27),
+                Stage::new(
+                    "humidity",
+                    "location",
+                    Converter::new(vec![Map::new(60, 56, 37), Map::new(56, 93, 4)]),
+                )
+                .at_line(31),
+            ],
+        );
         let file = std::fs::read_to_string("../test_input.txt").expect("Unable to read file");
         let actual = Almanac::from_string(&file);
         assert!(actual.is_ok());
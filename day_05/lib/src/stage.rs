@@ -0,0 +1,204 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::converter::Converter;
+use crate::diagnostic::{Diagnostic, Problem};
+use crate::map::Map;
+
+// A single `source-to-destination map:` section of an almanac
+#[derive(Debug, PartialEq)]
+pub struct Stage {
+    pub source: String,
+    pub destination: String,
+    pub converter: Converter,
+    // The line of the section header, when parsed
+    pub line: Option<usize>,
+}
+
+impl Stage {
+    pub fn new(source: &str, destination: &str, converter: Converter) -> Self {
+        Self {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            converter,
+            line: None,
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn section_name(&self) -> String {
+        format!("{}-to-{}", self.source, self.destination)
+    }
+
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let maps = self.converter.maps();
+        let mut problems = Vec::new();
+
+        if maps.iter().all(Map::is_empty) {
+            problems.push((None, Problem::EmptyStage));
+        }
+
+        // Overlaps can only be checked on maps whose ranges can be computed
+        let mut valid = Vec::new();
+        for (index, map) in maps.iter().enumerate() {
+            if map.overflows() {
+                problems.push((Some(index), Problem::Overflow { map: index }));
+            } else if !map.is_empty() {
+                valid.push(index);
+            }
+        }
+
+        for (first, second) in overlapping(maps, &valid, Map::source_range) {
+            problems.push((Some(second), Problem::OverlappingSources { first, second }));
+        }
+        for (first, second) in overlapping(maps, &valid, Map::destination_range) {
+            problems.push((
+                Some(second),
+                Problem::OverlappingDestinations { first, second },
+            ));
+        }
+
+        problems
+            .into_iter()
+            .map(|(map, problem)| Diagnostic {
+                section: self.section_name(),
+                line: self.line_of(map),
+                problem,
+            })
+            .collect()
+    }
+
+    // Maps are on consecutive lines straight after the header
+    fn line_of(&self, map: Option<usize>) -> Option<usize> {
+        self.line.map(|line| line + map.map_or(0, |map| map + 1))
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} map:\n{}", self.section_name(), self.converter)
+    }
+}
+
+// Pairs of maps whose ranges overlap, each pair ordered as the maps appear in the section
+fn overlapping(
+    maps: &[Map],
+    indices: &[usize],
+    range: fn(&Map) -> Range<usize>,
+) -> Vec<(usize, usize)> {
+    let mut sorted = indices.to_vec();
+    sorted.sort_by_key(|index| range(&maps[*index]).start);
+
+    let mut pairs = Vec::new();
+    for (position, index) in sorted.iter().enumerate() {
+        let end = range(&maps[*index]).end;
+        for other in sorted[position + 1..]
+            .iter()
+            .take_while(|other| range(&maps[**other]).start < end)
+        {
+            pairs.push((*index.min(other), *index.max(other)));
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_valid_stage() {
+        let stage = Stage::new(
+            "seed",
+            "soil",
+            Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
+        );
+
+        assert!(stage.validate().is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_sources() {
+        let stage = Stage::new(
+            "seed",
+            "soil",
+            Converter::new(vec![
+                Map::new(0, 10, 5),
+                Map::new(100, 0, 5),
+                Map::new(200, 12, 5),
+            ]),
+        )
+        .at_line(3);
+
+        assert_eq!(
+            stage.validate(),
+            vec![Diagnostic {
+                section: "seed-to-soil".to_string(),
+                line: Some(6),
+                problem: Problem::OverlappingSources {
+                    first: 0,
+                    second: 2
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_destinations() {
+        let stage = Stage::new(
+            "soil",
+            "water",
+            Converter::new(vec![Map::new(0, 10, 5), Map::new(4, 20, 5)]),
+        );
+
+        assert_eq!(
+            stage.validate(),
+            vec![Diagnostic {
+                section: "soil-to-water".to_string(),
+                line: None,
+                problem: Problem::OverlappingDestinations {
+                    first: 0,
+                    second: 1
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let stage = Stage::new(
+            "seed",
+            "soil",
+            Converter::new(vec![Map::new(0, 0, 5), Map::new(0, usize::MAX - 1, 5)]),
+        )
+        .at_line(1);
+
+        assert_eq!(
+            stage.validate(),
+            vec![Diagnostic {
+                section: "seed-to-soil".to_string(),
+                line: Some(3),
+                problem: Problem::Overflow { map: 1 },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_empty_stage() {
+        let stage = Stage::new("seed", "soil", Converter::new(vec![Map::new(4, 2, 0)])).at_line(9);
+
+        assert_eq!(
+            stage.validate(),
+            vec![Diagnostic {
+                section: "seed-to-soil".to_string(),
+                line: Some(9),
+                problem: Problem::EmptyStage,
+            }]
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{Context, Result};
+use lib::almanac::Almanac;
+
+pub fn calculate(file: &str) -> Result<usize> {
+    let almanac = Almanac::from_string(file).context("Unable to parse almanac")?;
+    let location_ranges = almanac
+        .calculate_location_ranges_with_seed_ranges()
+        .context("Unable to convert seeds to locations")?;
+    location_ranges
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .context("No seed locations found")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    const TEST_INPUT: &str = indoc! {"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+
+        water-to-light map:
+        88 18 7
+        18 25 70
+
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+    "};
+
+    #[test]
+    fn test_calculate() {
+        assert_eq!(46, calculate(TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_calculate_huge_ranges() {
+        let input = TEST_INPUT.replace("seeds: 79 14 55 13", "seeds: 90 1000000000000 1 5");
+        assert_eq!(19, calculate(&input).unwrap());
+    }
+
+    #[test]
+    fn test_calculate_rejects_overflowing_maps() {
+        let input = TEST_INPUT.replace("50 98 2", "0 18446744073709551614 5");
+        let error = calculate(&input).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unable to convert seeds to locations");
+        assert_eq!(
+            error.root_cause().to_string(),
+            "line 4 (seed-to-soil map): map 0 overflows"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+const SPEED: usize = 1; // mm/ms
+
+pub fn solve_part(input: &str) -> usize {
+    product_of_winners(&parse(input))
+}
+
+pub fn product_of_winners(races: &[(usize, usize)]) -> usize {
+    let mut product_of_winners = 1;
+
+    for &(time, record) in races {
+        let mut winners = 0;
+        for i in 0..=time {
+            let distance = get_distance_travelled(i, time);
+            if beats_record(distance, record) {
+                winners += 1;
+            }
+        }
+        product_of_winners *= winners;
+    }
+
+    product_of_winners
+}
+
+fn parse(input: &str) -> Vec<(usize, usize)> {
+    let mut lines = input.lines().map(|l| {
+        l.split_whitespace()
+            .skip(1)
+            .map(|n| n.parse::<usize>().expect("Race values should be numbers"))
+            .collect::<Vec<_>>()
+    });
+    let times = lines.next().expect("Input should have a Time line");
+    let records = lines.next().expect("Input should have a Distance line");
+
+    times.into_iter().zip(records).collect()
+}
+
+fn beats_record(distance: usize, record: usize) -> bool {
+    distance > record
+}
+
+fn get_distance_travelled(time_held: usize, total_time: usize) -> usize {
+    let time_remaining = total_time - time_held;
+    let velocity = SPEED * time_held;
+    velocity * time_remaining
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        assert_eq!(solve_part(input), 288);
+    }
+
+    #[test]
+    fn test_get_distance_travelled() {
+        assert_eq!(get_distance_travelled(0, 7), 0);
+        assert_eq!(get_distance_travelled(1, 7), 6);
+        assert_eq!(get_distance_travelled(2, 7), 10);
+        assert_eq!(get_distance_travelled(3, 7), 12);
+        assert_eq!(get_distance_travelled(4, 7), 12);
+        assert_eq!(get_distance_travelled(5, 7), 10);
+        assert_eq!(get_distance_travelled(6, 7), 6);
+        assert_eq!(get_distance_travelled(7, 7), 0);
+    }
+}
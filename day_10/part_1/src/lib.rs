@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use anyhow::{anyhow, Context, Result};
+use bitflags::bitflags;
+use grid::{Coordinate, Grid};
+
+pub fn solve_part(input: &str) -> usize {
+    let map = Map::try_from(input).expect("Failed to convert puzzle input to map");
+    let loop_tiles = depth_first_search(&map).expect("Failed to depth first search");
+    loop_tiles.len() / 2
+}
+
+fn depth_first_search(map: &Map) -> Result<HashSet<Coordinate>> {
+    let mut visited = HashSet::new();
+    let mut stack = Vec::new();
+
+    let start = map.start;
+
+    stack.push(start);
+
+    while let Some(tile) = stack.pop() {
+        if !visited.insert(tile) {
+            continue;
+        }
+
+        let neighbours = map.get_neighbours(tile)?;
+
+        for neighbour in neighbours {
+            stack.push(neighbour);
+        }
+    }
+
+    Ok(visited)
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct TileState: i64 {
+        const NONE = 0b00000000;
+        const UP = 0b00000001;
+        const DOWN = 0b00000010;
+        const LEFT = 0b00000100;
+        const RIGHT = 0b00001000;
+        const START = 0b00010000;
+    }
+}
+
+impl TryFrom<char> for TileState {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::NONE),
+            'S' => Ok(Self::START),
+            '|' => Ok(Self::UP | Self::DOWN),
+            '-' => Ok(Self::LEFT | Self::RIGHT),
+            'L' => Ok(Self::UP | Self::RIGHT),
+            'J' => Ok(Self::UP | Self::LEFT),
+            '7' => Ok(Self::DOWN | Self::LEFT),
+            'F' => Ok(Self::DOWN | Self::RIGHT),
+            _ => Err("Invalid character"),
+        }
+    }
+}
+
+struct Map {
+    tiles: Grid<TileState>,
+    start: Coordinate,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::parse_with(value, |symbol| {
+            TileState::try_from(symbol)
+                .map_err(|_| anyhow!("Failed to convert {} to TileState", symbol))
+        })
+        .map_err(|e| format!("{:#}", e))?;
+
+        let start = tiles
+            .iter()
+            .find(|(_, tile_state)| **tile_state == TileState::START)
+            .map(|(coordinate, _)| coordinate)
+            .ok_or_else(|| "No start found".to_string())?;
+
+        let mut map = Map { tiles, start };
+
+        map.calculate_start_neighbours();
+
+        Ok(map)
+    }
+}
+
+impl Map {
+    fn calculate_start_neighbours(&mut self) {
+        let start = self.start;
+
+        let directions = [
+            ((0, 1), TileState::UP, TileState::DOWN),
+            ((0, -1), TileState::DOWN, TileState::UP),
+            ((1, 0), TileState::LEFT, TileState::RIGHT),
+            ((-1, 0), TileState::RIGHT, TileState::LEFT),
+        ];
+
+        for ((dx, dy), valid_incoming, outgoing_state) in directions {
+            if let Some(neighbour) = self.tiles.offset(start, dx, dy) {
+                if self.tiles[neighbour].contains(valid_incoming) {
+                    self.tiles[start] |= outgoing_state;
+                }
+            }
+        }
+    }
+
+    fn get_neighbours(&self, tile: Coordinate) -> Result<impl Iterator<Item = Coordinate> + '_> {
+        let tile_state = self.tiles.get(tile).context("Tile not found")?;
+
+        let directions = [
+            (TileState::UP, (0, -1)),
+            (TileState::DOWN, (0, 1)),
+            (TileState::LEFT, (-1, 0)),
+            (TileState::RIGHT, (1, 0)),
+        ];
+
+        // Pipes pointing off the edge of the map have no neighbour in that direction
+        Ok(directions.into_iter().filter_map(move |(state, (dx, dy))| {
+            if tile_state.contains(state) {
+                self.tiles.offset(tile, dx, dy)
+            } else {
+                None
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_tile_state_from_char_valid() {
+        assert_eq!(TileState::try_from('.').unwrap(), TileState::NONE);
+        assert_eq!(TileState::try_from('S').unwrap(), TileState::START);
+        assert_eq!(
+            TileState::try_from('|').unwrap(),
+            TileState::UP | TileState::DOWN
+        );
+        assert_eq!(
+            TileState::try_from('-').unwrap(),
+            TileState::LEFT | TileState::RIGHT
+        );
+        assert_eq!(
+            TileState::try_from('L').unwrap(),
+            TileState::UP | TileState::RIGHT
+        );
+        assert_eq!(
+            TileState::try_from('J').unwrap(),
+            TileState::UP | TileState::LEFT
+        );
+        assert_eq!(
+            TileState::try_from('7').unwrap(),
+            TileState::DOWN | TileState::LEFT
+        );
+        assert_eq!(
+            TileState::try_from('F').unwrap(),
+            TileState::DOWN | TileState::RIGHT
+        );
+    }
+
+    #[test]
+    fn test_tile_state_from_char_invalid_returns_error() {
+        assert!(TileState::try_from('a').is_err());
+    }
+
+    #[test]
+    fn test_map_from_str_valid() {
+        let map = Map::try_from(indoc!(
+            "
+            S.|.|
+            -LJ-F
+            |..|.
+            "
+        ))
+        .unwrap();
+
+        assert_eq!(map.tiles.len(), 15);
+        assert!(map.tiles[Coordinate::new(0, 0)].contains(TileState::START));
+        assert!(map.tiles[Coordinate::new(1, 0)].contains(TileState::NONE));
+        assert!(map.tiles[Coordinate::new(2, 0)].contains(TileState::UP | TileState::DOWN));
+        assert!(map.tiles[Coordinate::new(3, 0)].contains(TileState::NONE));
+        assert!(map.tiles[Coordinate::new(4, 0)].contains(TileState::UP | TileState::DOWN));
+        assert!(map.tiles[Coordinate::new(0, 1)].contains(TileState::LEFT | TileState::RIGHT));
+        assert!(map.tiles[Coordinate::new(1, 1)].contains(TileState::UP | TileState::RIGHT));
+        assert!(map.tiles[Coordinate::new(2, 1)].contains(TileState::UP | TileState::LEFT));
+        assert!(map.tiles[Coordinate::new(3, 1)].contains(TileState::LEFT | TileState::RIGHT));
+        assert!(map.tiles[Coordinate::new(4, 1)].contains(TileState::DOWN | TileState::RIGHT));
+        assert!(map.tiles[Coordinate::new(0, 2)].contains(TileState::UP | TileState::DOWN));
+        assert!(map.tiles[Coordinate::new(1, 2)].contains(TileState::NONE));
+        assert!(map.tiles[Coordinate::new(2, 2)].contains(TileState::NONE));
+        assert!(map.tiles[Coordinate::new(3, 2)].contains(TileState::UP | TileState::DOWN));
+        assert!(map.tiles[Coordinate::new(4, 2)].contains(TileState::NONE));
+    }
+
+    #[test]
+    fn test_map_from_str_invalid_returns_error() {
+        let map = Map::try_from(indoc!(
+            "
+            S.|.|
+            -LJ-F
+            |..|.
+            "
+        ));
+
+        assert!(map.is_ok());
+    }
+
+    #[test]
+    fn test_calculate_starting_neighbours() {
+        let map = Map::try_from(indoc!(
+            "
+            ..F7.
+            .FJ|.
+            SJ.L7
+            |F--J
+            LJ...
+            "
+        ))
+        .unwrap();
+
+        assert!(map.tiles[Coordinate::new(0, 2)].contains(TileState::START));
+        assert!(map.tiles[Coordinate::new(0, 2)].contains(TileState::RIGHT | TileState::DOWN));
+    }
+
+    #[test]
+    fn test_get_most_distant_tile() {
+        let map = Map::try_from(indoc!(
+            "
+            ..F7.
+            .FJ|.
+            SJ.L7
+            |F--J
+            LJ...
+            "
+        ))
+        .unwrap();
+
+        let loop_tiles = dbg!(depth_first_search(&map).unwrap());
+
+        assert_eq!(8, loop_tiles.len() / 2);
+    }
+
+    #[test]
+    fn test_get_neighbours() {
+        let map = Map::try_from(indoc!(
+            "
+            ..F7.
+            .FJ|.
+            SJ.L7
+            |F--J
+            LJ...
+            "
+        ))
+        .unwrap();
+
+        let neighbours = map
+            .get_neighbours(Coordinate::new(0, 2))
+            .unwrap()
+            .collect::<Vec<_>>();
+        dbg!(&map.tiles[Coordinate::new(0, 2)]);
+        assert_eq!(neighbours.len(), 2);
+        assert!(neighbours.contains(&Coordinate::new(1, 2)));
+        assert!(neighbours.contains(&Coordinate::new(0, 3)));
+    }
+}
@@ -0,0 +1,175 @@
+use anyhow::{Context, Result};
+use grid::{Coordinate, Grid};
+
+pub fn solve_part(input: &str) -> usize {
+    let map = parse_input(input).expect("Unable to parse input");
+    cumalative_distance_for_pairs(map)
+}
+
+fn cumalative_distance_for_pairs(points: Vec<Coordinate>) -> usize {
+    let mut total_distance = 0;
+    for i in 0..points.len() - 1 {
+        for j in i + 1..points.len() {
+            total_distance += points[i].manhattan_distance(points[j]);
+        }
+    }
+    total_distance
+}
+
+// Could be optimised by tracking empty rows and columns during parsing
+fn parse_input(input: &str) -> Result<Vec<Coordinate>> {
+    let image = Grid::parse_with(input, |c| Ok(c == '#'))?;
+    let mut galaxies: Vec<Coordinate> = image
+        .iter()
+        .filter(|(_, is_galaxy)| **is_galaxy)
+        .map(|(coordinate, _)| coordinate)
+        .collect();
+
+    let mut max_x = galaxies.iter().map(|c| c.x).max().context("No max x")?;
+    let mut max_y = galaxies.iter().map(|c| c.y).max().context("No max y")?;
+
+    let mut y = 0;
+    // Expand the map by one row after each empty row
+    while y <= max_y {
+        // Check if there are any galaxies on this row
+        if !galaxies.iter().any(|c| c.y == y) {
+            // No galaxies on this row, so add one to the y coordinate of all galaxies above this row
+            galaxies
+                .iter_mut()
+                .filter(|c| c.y > y)
+                .for_each(|c| c.y += 1);
+            max_y += 1;
+            // Skip the next row to avoid infinite expansion
+            y += 2;
+        } else {
+            y += 1;
+        }
+    }
+
+    let mut x = 0;
+    // Expand the map by one column after each empty column
+    while x <= max_x {
+        // Check if there are any galaxies in this column
+        if !galaxies.iter().any(|c| c.x == x) {
+            // No galaxies in this column, so add one to the x coordinate of all galaxies above this column
+            galaxies
+                .iter_mut()
+                .filter(|c| c.x > x)
+                .for_each(|c| c.x += 1);
+            max_x += 1;
+            // Skip the next row to avoid infinite expansion
+            x += 2;
+        } else {
+            x += 1;
+        }
+    }
+
+    Ok(galaxies)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    #[test]
+    fn test_get_manhattan_distance_same_position_should_equal_zero() {
+        let start = Coordinate::new(0, 0);
+        let end = Coordinate::new(0, 0);
+        let expected = 0;
+
+        assert_eq!(expected, start.manhattan_distance(end));
+    }
+
+    #[test]
+    fn test_get_manhattan_distance_positive_start() {
+        let start = Coordinate::new(0, 0);
+        let end = Coordinate::new(1, 1);
+        let expected = 2;
+
+        assert_eq!(expected, start.manhattan_distance(end));
+    }
+
+    #[test]
+    fn test_get_manhattan_distance_offset_start() {
+        let start = Coordinate::new(1, 1);
+        let end = Coordinate::new(4, 4);
+        let expected = 6;
+
+        assert_eq!(expected, start.manhattan_distance(end));
+    }
+
+    #[test]
+    fn test_cumalative_distance_for_pairs() {
+        let points = vec![
+            Coordinate::new(4, 0),
+            Coordinate::new(9, 1),
+            Coordinate::new(0, 2),
+            Coordinate::new(8, 5),
+            Coordinate::new(1, 6),
+            Coordinate::new(12, 7),
+            Coordinate::new(9, 10),
+            Coordinate::new(0, 11),
+            Coordinate::new(5, 11),
+        ];
+        let expected = 374;
+
+        assert_eq!(expected, cumalative_distance_for_pairs(points));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = indoc!(
+            "
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+            "
+        );
+
+        let expected = vec![
+            Coordinate::new(4, 0),
+            Coordinate::new(9, 1),
+            Coordinate::new(0, 2),
+            Coordinate::new(8, 5),
+            Coordinate::new(1, 6),
+            Coordinate::new(12, 7),
+            Coordinate::new(9, 10),
+            Coordinate::new(0, 11),
+            Coordinate::new(5, 11),
+        ];
+
+        assert_eq!(expected, parse_input(input).unwrap());
+    }
+
+    #[test]
+    fn test_parse_to_culumative_distance() {
+        let input = indoc!(
+            "
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+            "
+        );
+
+        let expected = 374;
+
+        let input = parse_input(input).unwrap();
+        let actual = cumalative_distance_for_pairs(input);
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,278 @@
+use grid::{Coordinate, Grid};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+pub fn solve_part(input: &str) -> usize {
+    let mut map = Map::parse_input(input);
+    map.set_expansion_factor(1_000_000);
+    map.cumulative_distance_for_pairs()
+}
+
+trait ExpandedDistance {
+    fn get_manhattan_distance_with_expansion_factor(
+        &self,
+        other: &Coordinate,
+        empty_rows: &HashSet<usize>,
+        empty_cols: &HashSet<usize>,
+        expansion_factor: usize,
+    ) -> usize;
+}
+
+impl ExpandedDistance for Coordinate {
+    fn get_manhattan_distance_with_expansion_factor(
+        &self,
+        other: &Coordinate,
+        empty_rows: &HashSet<usize>,
+        empty_cols: &HashSet<usize>,
+        expansion_factor: usize,
+    ) -> usize {
+        let distance = self.manhattan_distance(*other);
+
+        let empty_row_crosses = (self.y.min(other.y)..=self.y.max(other.y))
+            .filter(|r| empty_rows.contains(r))
+            .count();
+        let empty_col_crosses = (self.x.min(other.x)..=self.x.max(other.x))
+            .filter(|c| empty_cols.contains(c))
+            .count();
+        let total_crosses = empty_row_crosses + empty_col_crosses;
+
+        // We need to subtract the number of crossings so that they aren't counted twice
+        (distance - total_crosses) + expansion_factor * total_crosses
+    }
+}
+
+struct Map {
+    galaxies: Vec<Coordinate>,
+    empty_rows: HashSet<usize>,
+    empty_columns: HashSet<usize>,
+    expansion_factor: usize,
+}
+
+impl Map {
+    fn parse_input(input: &str) -> Map {
+        let image = Grid::parse_with(input, |c| Ok(c == '#')).expect("Unable to parse input");
+
+        let galaxies = image
+            .iter()
+            .filter(|(_, is_galaxy)| **is_galaxy)
+            .map(|(coordinate, _)| coordinate)
+            .collect();
+
+        let empty_columns = image
+            .columns()
+            .enumerate()
+            .filter(|(_, column)| !column.clone().any(|is_galaxy| *is_galaxy))
+            .map(|(x, _)| x)
+            .collect();
+
+        let empty_rows = image
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| !row.contains(&true))
+            .map(|(y, _)| y)
+            .collect();
+
+        Map {
+            galaxies,
+            empty_rows,
+            empty_columns,
+            expansion_factor: 1,
+        }
+    }
+
+    fn set_expansion_factor(&mut self, factor: usize) {
+        self.expansion_factor = factor;
+    }
+
+    // Original Implementation
+    // fn cumulative_distance_for_pairs(&self) -> usize {
+    //     let mut total_distance = 0;
+    //     for i in 0..self.galaxies.len() - 1 {
+    //         for j in i + 1..self.galaxies.len() {
+    //             total_distance += self.galaxies[i].get_manhattan_distance_with_expansion_factor(
+    //                 &self.galaxies[j],
+    //                 &self.empty_rows,
+    //                 &self.empty_columns,
+    //                 self.expansion_factor,
+    //             );
+    //         }
+    //     }
+    //     total_distance
+    // }
+
+    // Functional Implementation with Rayon
+    fn cumulative_distance_for_pairs(&self) -> usize {
+        let galaxies = &self.galaxies;
+        let empty_rows = &self.empty_rows;
+        let empty_cols = &self.empty_columns;
+        let expansion_factor = self.expansion_factor;
+
+        (0..galaxies.len() - 1)
+            .into_par_iter()
+            .map_init(
+                || galaxies,
+                |galaxies, i| {
+                    (i + 1..galaxies.len())
+                        .map(|j| {
+                            galaxies[i].get_manhattan_distance_with_expansion_factor(
+                                &galaxies[j],
+                                empty_rows,
+                                empty_cols,
+                                expansion_factor,
+                            )
+                        })
+                        .sum::<usize>()
+                },
+            )
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse_input_to_map() {
+        let input = indoc!(
+            "
+            #....
+            .....
+            ..#.#
+            .....
+            ....#
+            "
+        );
+
+        let expected_coordinates = vec![
+            Coordinate::new(0, 0),
+            Coordinate::new(2, 2),
+            Coordinate::new(4, 2),
+            Coordinate::new(4, 4),
+        ];
+        let expected_empty_rows: HashSet<usize> = vec![1, 3].into_iter().collect();
+        let expected_empty_columns: HashSet<usize> = vec![1, 3].into_iter().collect();
+        let expected_expansion_factor = 1;
+
+        let actual = Map::parse_input(input);
+        let actual_coordinates = actual.galaxies;
+        let actual_empty_rows = actual.empty_rows;
+        let actual_empty_columns = actual.empty_columns;
+        let actual_expansion_factor = actual.expansion_factor;
+
+        assert_eq!(actual_coordinates, expected_coordinates);
+        assert_eq!(actual_empty_rows, expected_empty_rows);
+        assert_eq!(actual_empty_columns, expected_empty_columns);
+        assert_eq!(actual_expansion_factor, expected_expansion_factor);
+    }
+
+    #[test]
+    fn test_get_manhattan_distance_with_expansion_factor_no_crossings() {
+        let pair = (Coordinate::new(0, 0), Coordinate::new(1, 0));
+        let empty_cols: HashSet<usize> = HashSet::new();
+        let empty_rows: HashSet<usize> = HashSet::new();
+        let expansion_factor = 1;
+
+        let expected = 1;
+        let actual = pair.0.get_manhattan_distance_with_expansion_factor(
+            &pair.1,
+            &empty_cols,
+            &empty_rows,
+            expansion_factor,
+        );
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_get_manhattan_distance_with_triple_expansion_factor_two_crossings() {
+        let pair = (Coordinate::new(0, 0), Coordinate::new(2, 2));
+        let empty_cols: HashSet<usize> = vec![1].into_iter().collect();
+        let empty_rows: HashSet<usize> = vec![1].into_iter().collect();
+        let expansion_factor = 3;
+
+        let expected = 8;
+        let actual = pair.0.get_manhattan_distance_with_expansion_factor(
+            &pair.1,
+            &empty_rows,
+            &empty_cols,
+            expansion_factor,
+        );
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_complex_input() {
+        let input = indoc!(
+            "
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+            "
+        );
+
+        let expected_coordinates = vec![
+            Coordinate::new(3, 0),
+            Coordinate::new(7, 1),
+            Coordinate::new(0, 2),
+            Coordinate::new(6, 4),
+            Coordinate::new(1, 5),
+            Coordinate::new(9, 6),
+            Coordinate::new(7, 8),
+            Coordinate::new(0, 9),
+            Coordinate::new(4, 9),
+        ];
+        let expected_empty_rows: HashSet<usize> = vec![3, 7].into_iter().collect();
+        let expected_empty_columns: HashSet<usize> = vec![2, 5, 8].into_iter().collect();
+        let expected_expansion_factor = 1;
+
+        let actual = Map::parse_input(input);
+        let actual_coordinates = actual.galaxies;
+        let actual_empty_rows = actual.empty_rows;
+        let actual_empty_columns = actual.empty_columns;
+        let actual_expansion_factor = actual.expansion_factor;
+
+        assert_eq!(actual_coordinates, expected_coordinates);
+        assert_eq!(actual_empty_rows, expected_empty_rows);
+        assert_eq!(actual_empty_columns, expected_empty_columns);
+        assert_eq!(actual_expansion_factor, expected_expansion_factor);
+    }
+
+    #[test]
+    fn test_parse_complex_input_and_find_pairs() {
+        let input = indoc!(
+            "
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+            "
+        );
+
+        let expected = 1030;
+        let mut input = Map::parse_input(input);
+        input.set_expansion_factor(10);
+        let actual = input.cumulative_distance_for_pairs();
+        assert_eq!(expected, actual);
+
+        let expected = 8410;
+        input.set_expansion_factor(100);
+        let actual = input.cumulative_distance_for_pairs();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::{anyhow, Result};
+use grid::Grid;
+
+pub fn solve_part(input: &str) -> usize {
+    let mut platform = parse(input).expect("Failed to parse input");
+    platform.rows_mut().for_each(slide_rocks);
+    platform.rows().map(calculate_load).sum()
+}
+
+// Rotating clockwise turns each column into a row running from south to north,
+// so rocks sliding towards the end of a row are sliding north
+fn parse(input: &str) -> Result<Grid<PositionState>> {
+    let platform: Grid<PositionState> = input.parse()?;
+    Ok(platform.rotate_clockwise())
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum PositionState {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+
+impl TryFrom<char> for PositionState {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Self::RoundRock),
+            '#' => Ok(Self::CubeRock),
+            '.' => Ok(Self::Empty),
+            _ => Err(anyhow!("Position can only be one of 'O', '#' or '.'")),
+        }
+    }
+}
+
+fn slide_rocks(positions: &mut [PositionState]) {
+    // We know that our positions should not be empty
+    assert!(!positions.is_empty());
+    // We start counting from the end to enable easier cascading
+    let mut current_position = positions.len();
+    // Initialise our blocker pointer to None
+    let mut last_available_space = None;
+
+    // Loop through our positions excluding the last position
+    for _ in 0..positions.len() {
+        current_position -= 1;
+        let position_state = positions[current_position];
+
+        if let Some(last_space_index) = last_available_space {
+            // A space to slide is available
+            match position_state {
+                PositionState::RoundRock => {
+                    positions[last_space_index] = PositionState::RoundRock;
+                    positions[current_position] = PositionState::Empty;
+                    last_available_space = Some(last_space_index - 1);
+                }
+                PositionState::CubeRock => last_available_space = None,
+                PositionState::Empty => {}
+            }
+        } else {
+            // Nowhere to slide, so we only care about empty spaces
+            if position_state == PositionState::Empty {
+                last_available_space = Some(current_position);
+            }
+        }
+    }
+}
+
+fn calculate_load(positions: &[PositionState]) -> usize {
+    positions
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (position, state)| {
+            if state == &PositionState::RoundRock {
+                acc + position + 1
+            } else {
+                acc
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve_part() {
+        let input = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+        "};
+        assert_eq!(solve_part(input), 136);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = indoc! {"
+        O.#
+        #..
+        .O#
+        "};
+
+        let expected = vec![
+            vec![
+                PositionState::Empty,
+                PositionState::CubeRock,
+                PositionState::RoundRock,
+            ],
+            vec![
+                PositionState::RoundRock,
+                PositionState::Empty,
+                PositionState::Empty,
+            ],
+            vec![
+                PositionState::CubeRock,
+                PositionState::Empty,
+                PositionState::CubeRock,
+            ],
+        ];
+
+        let expected = Grid::from_rows(expected).unwrap();
+        let actual = parse(input).expect("Testing input should not fail to parse");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_round_rocks_slide_to_correct_positions() {
+        let mut line = vec![
+            PositionState::RoundRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::CubeRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+        ];
+        let expected = vec![
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::RoundRock,
+            PositionState::CubeRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+        ];
+
+        slide_rocks(&mut line);
+
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn test_calculate_load_returns_correct_value() {
+        let mut line = vec![
+            PositionState::RoundRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::CubeRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+        ];
+        // Before Sliding
+        let expected = 1;
+        let actual = calculate_load(&line);
+        assert_eq!(actual, expected);
+        // After Sliding
+        let expected = 5;
+        slide_rocks(&mut line);
+        let actual = calculate_load(&line);
+        assert_eq!(actual, expected);
+    }
+}
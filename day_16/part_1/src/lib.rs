@@ -0,0 +1,208 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Error, Ok, Result};
+use grid::{Coordinate, Grid};
+
+pub fn solve_part(input: &str) -> usize {
+    let grid = input.parse::<Grid<Tile>>().expect("Input should be valid");
+    let energized_tiles = get_energized_tiles(&grid);
+    energized_tiles.len()
+}
+
+#[derive(Debug, PartialEq)]
+enum Tile {
+    Empty,
+    MirrorForward,
+    MirrorBackward,
+    SplitterHorizontal,
+    SplitterVertical,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::MirrorForward),
+            '\\' => Ok(Tile::MirrorBackward),
+            '-' => Ok(Tile::SplitterHorizontal),
+            '|' => Ok(Tile::SplitterVertical),
+            _ => Err(anyhow!("Failed to parse Tile from char")),
+        }
+    }
+}
+
+fn get_energized_tiles(grid: &Grid<Tile>) -> HashSet<Coordinate> {
+    let mut energized_tiles = HashSet::new();
+    let mut seen_moves = HashSet::new();
+    let current = Coordinate::new(0, 0);
+    let origin = Origin::West;
+
+    let mut moves = Vec::new();
+    moves.push((current, origin));
+    seen_moves.insert((current, origin));
+
+    while let Some((current, origin)) = moves.pop() {
+        energized_tiles.insert(current);
+        let (move_1, move_2) = next_steps(grid, current, origin);
+        if let Some((next, origin)) = move_1 {
+            if !seen_moves.contains(&(next, origin)) {
+                moves.push((next, origin));
+                seen_moves.insert((next, origin));
+            }
+        }
+        if let Some((next, origin)) = move_2 {
+            if !seen_moves.contains(&(next, origin)) {
+                moves.push((next, origin));
+                seen_moves.insert((next, origin));
+            }
+        }
+    }
+    energized_tiles
+}
+
+// The next position a beam moves to and the side it enters from
+type Step = (Coordinate, Origin);
+
+fn continue_in_direction(grid: &Grid<Tile>, previous: Coordinate, origin: Origin) -> Option<Step> {
+    let (dx, dy) = origin.direction_of_travel();
+    grid.offset(previous, dx, dy).map(|next| (next, origin))
+}
+
+fn next_steps(
+    grid: &Grid<Tile>,
+    current: Coordinate,
+    origin: Origin,
+) -> (Option<Step>, Option<Step>) {
+    let continue_in_direction = |direction| continue_in_direction(grid, current, direction);
+    match grid[current] {
+        Tile::Empty => (continue_in_direction(origin), None),
+        Tile::MirrorForward => match origin {
+            Origin::North => (continue_in_direction(Origin::East), None),
+            Origin::East => (continue_in_direction(Origin::North), None),
+            Origin::South => (continue_in_direction(Origin::West), None),
+            Origin::West => (continue_in_direction(Origin::South), None),
+        },
+        Tile::MirrorBackward => match origin {
+            Origin::North => (continue_in_direction(Origin::West), None),
+            Origin::East => (continue_in_direction(Origin::South), None),
+            Origin::South => (continue_in_direction(Origin::East), None),
+            Origin::West => (continue_in_direction(Origin::North), None),
+        },
+        Tile::SplitterHorizontal => match origin {
+            Origin::North | Origin::South => (
+                continue_in_direction(Origin::East),
+                continue_in_direction(Origin::West),
+            ),
+            Origin::East | Origin::West => (continue_in_direction(origin), None),
+        },
+        Tile::SplitterVertical => match origin {
+            Origin::East | Origin::West => (
+                continue_in_direction(Origin::North),
+                continue_in_direction(Origin::South),
+            ),
+            Origin::North | Origin::South => (continue_in_direction(origin), None),
+        },
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Origin {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Origin {
+    // A beam entering from the north is travelling south, and so on
+    fn direction_of_travel(self) -> (isize, isize) {
+        match self {
+            Origin::North => (0, 1),
+            Origin::East => (-1, 0),
+            Origin::South => (0, -1),
+            Origin::West => (1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve_part() {
+        let input = indoc! {r#"
+        .|...\....
+        |.-.\.....
+        .....|-...
+        ........|.
+        ..........
+        .........\
+        ..../.\\..
+        .-.-/..|..
+        .|....-|.\
+        ..//.|....
+        "#};
+        let grid = input
+            .parse::<Grid<Tile>>()
+            .expect("Test Input should be valid");
+        assert_eq!((grid.width(), grid.height()), (10, 10));
+
+        assert_eq!(solve_part(input), 46);
+    }
+
+    #[test]
+    fn test_energized_tiles() {
+        // We need to manually escape the backslash in indoc
+        let input = indoc! {"
+        .|-
+        /|/
+        /-/
+        "};
+        let grid = input
+            .parse::<Grid<Tile>>()
+            .expect("Test Input should be valid");
+        let energized_tiles = get_energized_tiles(&grid);
+        let expected_count = 7;
+        assert_eq!(energized_tiles.len(), expected_count);
+    }
+
+    #[test]
+    fn test_parse_tile_from_char() {
+        assert_eq!(Tile::try_from('.').unwrap(), Tile::Empty);
+        assert_eq!(Tile::try_from('/').unwrap(), Tile::MirrorForward);
+        assert_eq!(Tile::try_from('\\').unwrap(), Tile::MirrorBackward);
+        assert_eq!(Tile::try_from('-').unwrap(), Tile::SplitterHorizontal);
+        assert_eq!(Tile::try_from('|').unwrap(), Tile::SplitterVertical);
+    }
+
+    #[test]
+    fn test_parse_tile_from_char_returns_error_invalid_characters() {
+        assert!(Tile::try_from('d').is_err());
+    }
+
+    #[test]
+    fn test_parse_grid_from_string() {
+        // We need to manually escape the backslash in indoc
+        let input = indoc! {"
+        .|/
+        -..
+        |.\\
+        "};
+        let actual = input
+            .parse::<Grid<Tile>>()
+            .expect("Test Input should be valid");
+        let expected = Grid::from_rows(vec![
+            vec![Tile::Empty, Tile::SplitterVertical, Tile::MirrorForward],
+            vec![Tile::SplitterHorizontal, Tile::Empty, Tile::Empty],
+            vec![Tile::SplitterVertical, Tile::Empty, Tile::MirrorBackward],
+        ])
+        .unwrap();
+        assert_eq!(actual.len(), expected.len());
+        assert_eq!((actual.width(), actual.height()), (3, 3));
+        assert_eq!(actual, expected);
+    }
+}
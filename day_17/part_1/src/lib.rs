@@ -0,0 +1,257 @@
+use std::{cmp::Reverse, collections::HashSet};
+
+use anyhow::{anyhow, Result};
+use grid::{Coordinate, Grid};
+use priority_queue::PriorityQueue;
+
+pub fn solve_part(input: &str) -> usize {
+    let grid = parse(input).expect("Input should be valid grid");
+    let end = (grid.width() - 1, grid.height() - 1).into();
+    let search = SearchParameters {
+        start: (0, 0).into(),
+        end,
+        max_movement: 3,
+    };
+    find_lowest_heatloss(&grid, search).expect("There should be a correct path")
+}
+
+fn parse(input: &str) -> Result<Grid<usize>> {
+    Grid::parse_with(input, |c| {
+        c.to_digit(10)
+            .map(|cell| cell as usize)
+            .ok_or_else(|| anyhow!("Invalid digit in input"))
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Direction {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Move {
+    position: Coordinate,
+    direction: Option<Direction>,
+}
+
+impl Move {
+    fn new(position: Coordinate, direction: Option<Direction>) -> Self {
+        Self {
+            position,
+            direction,
+        }
+    }
+}
+
+struct SearchParameters {
+    start: Coordinate,
+    end: Coordinate,
+    max_movement: usize,
+}
+
+// Dijkstras algorithm, where each cell of the grid is the heat lost by entering that city block
+fn find_lowest_heatloss(grid: &Grid<usize>, search: SearchParameters) -> Option<usize> {
+    // HashSet of Moves
+    let mut explored = HashSet::new();
+    // PriorityQueue of Moves that need to be explored
+    let mut frontier: PriorityQueue<Move, Reverse<usize>> = PriorityQueue::new();
+    // Set up the first move and add it to the frontier
+    let first_move = Move::new(search.start, None);
+    frontier.push(first_move, Reverse(0));
+    // Loop while there are possible moves in the frontier
+    while let Some((m, Reverse(c))) = frontier.pop() {
+        // Check to see if we have reached the goal
+        if m.position == search.end {
+            return Some(c);
+        }
+        // Otherwise we need to add this move to the explored set
+        explored.insert(m);
+        // Then get all the possible successor moves
+        // Automatically excludes already explored moves
+        let successors = successors(grid, m, c, search.max_movement, &explored);
+        // Loop over every successor move
+        for (suc_mov, suc_cost) in successors {
+            // We try and increase the priority of whatever is already in the frontier
+            // If the priority isn't an increase, then we discard the result
+            frontier.push_increase(suc_mov, Reverse(suc_cost));
+        }
+    }
+
+    // If there was no early exit then we can assume that no route was discovered
+    // This shouldn't ever happen with our test data
+    None
+}
+
+fn successors(
+    grid: &Grid<usize>,
+    previous_move: Move,
+    previous_move_cost: usize,
+    max_movement: usize,
+    explored: &HashSet<Move>,
+) -> Vec<(Move, usize)> {
+    let mut successors = Vec::new();
+    // For tracking the increasing costs in each direction E W S N
+    let mut culm_costs = (
+        previous_move_cost,
+        previous_move_cost,
+        previous_move_cost,
+        previous_move_cost,
+    );
+
+    // We need to run the loop from 1 to the max movement depth
+    for d in 1..=max_movement {
+        // Handle Horizontal movements
+        if previous_move.direction != Some(Direction::Horizontal) {
+            // Positive Direction (East)
+            if let Some(pos) = grid.offset(previous_move.position, d as isize, 0) {
+                // We need to update the culm score in this direction
+                culm_costs.0 += grid[pos];
+                // Create a new move
+                let new_move = Move::new(pos, Some(Direction::Horizontal));
+                // Check if we have already explored from this direction
+                if !explored.contains(&new_move) {
+                    successors.push((new_move, culm_costs.0))
+                }
+            }
+            // Negative Direction (West)
+            if let Some(pos) = grid.offset(previous_move.position, -(d as isize), 0) {
+                // We need to update the culm score in this direction
+                culm_costs.1 += grid[pos];
+                // Create a new move
+                let new_move = Move::new(pos, Some(Direction::Horizontal));
+                // Check if we have already explored from this direction
+                if !explored.contains(&new_move) {
+                    successors.push((new_move, culm_costs.1))
+                }
+            }
+        }
+        if previous_move.direction != Some(Direction::Vertical) {
+            // Positive Direction (South)
+            if let Some(pos) = grid.offset(previous_move.position, 0, d as isize) {
+                culm_costs.2 += grid[pos];
+                let new_move = Move::new(pos, Some(Direction::Vertical));
+                if !explored.contains(&new_move) {
+                    successors.push((new_move, culm_costs.2))
+                }
+            }
+            // Negative Direction (North)
+            if let Some(pos) = grid.offset(previous_move.position, 0, -(d as isize)) {
+                culm_costs.3 += grid[pos];
+                let new_move = Move::new(pos, Some(Direction::Vertical));
+                if !explored.contains(&new_move) {
+                    successors.push((new_move, culm_costs.3))
+                }
+            }
+        }
+    }
+
+    successors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse_grid() {
+        let input = indoc! {"
+        2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533
+        "};
+
+        let grid = parse(input).expect("Input should be valid grid");
+        assert_eq!(grid.width(), 13);
+        assert_eq!(grid.height(), 13);
+        assert_eq!(grid.len(), 13 * 13);
+        // First row
+        assert_eq!(grid[(0, 0).into()], 2);
+        assert_eq!(grid[(1, 0).into()], 4);
+        assert_eq!(grid[(2, 0).into()], 1);
+        assert_eq!(grid[(3, 0).into()], 3);
+        assert_eq!(grid[(4, 0).into()], 4);
+        assert_eq!(grid[(5, 0).into()], 3);
+        assert_eq!(grid[(6, 0).into()], 2);
+        assert_eq!(grid[(7, 0).into()], 3);
+        assert_eq!(grid[(8, 0).into()], 1);
+        assert_eq!(grid[(9, 0).into()], 1);
+        assert_eq!(grid[(10, 0).into()], 3);
+        assert_eq!(grid[(11, 0).into()], 2);
+        assert_eq!(grid[(12, 0).into()], 3);
+    }
+
+    #[test]
+    fn test_successors() {
+        let input = indoc! {"
+        2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533
+        "};
+
+        let grid = parse(input).expect("Input should be valid grid");
+        let start = (0, 0).into();
+        let move_1 = Move::new(start, None);
+        let explored = HashSet::new();
+        let successors: Vec<(Move, usize)> = successors(&grid, move_1, 2, 3, &explored);
+        let expected = vec![
+            (Move::new((1, 0).into(), Some(Direction::Horizontal)), 6),
+            (Move::new((0, 1).into(), Some(Direction::Vertical)), 5),
+            (Move::new((2, 0).into(), Some(Direction::Horizontal)), 7),
+            (Move::new((0, 2).into(), Some(Direction::Vertical)), 8),
+            (Move::new((3, 0).into(), Some(Direction::Horizontal)), 10),
+            (Move::new((0, 3).into(), Some(Direction::Vertical)), 11),
+        ];
+        assert_eq!(successors.len(), 6);
+        assert_eq!(successors, expected);
+    }
+
+    #[test]
+    fn test_find_lowest_heatloss() {
+        let input = indoc! {"
+        2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533
+        "};
+
+        let grid = parse(input).expect("Input should be valid grid");
+        let search = SearchParameters {
+            start: (0, 0).into(),
+            end: (12, 12).into(),
+            max_movement: 3,
+        };
+        let result = find_lowest_heatloss(&grid, search).expect("There should be a correct path");
+        assert_eq!(result, 102);
+    }
+}
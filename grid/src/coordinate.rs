@@ -0,0 +1,93 @@
+// Starting at the top left corner x is zero and counts up as you move right
+// Starting at the top left corner y is zero and counts up as you move down
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Coordinate {
+    pub x: usize,
+    pub y: usize,
+}
+
+// Offsets to the orthogonal neighbours, clockwise from north
+pub(crate) const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// Offsets to all surrounding neighbours, clockwise from north
+pub(crate) const SURROUNDING_OFFSETS: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+impl Coordinate {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    // Only guards against leaving the range of usize, use Grid::offset to stay inside a grid
+    pub fn checked_offset(self, dx: isize, dy: isize) -> Option<Coordinate> {
+        let x = self.x.checked_add_signed(dx)?;
+        let y = self.y.checked_add_signed(dy)?;
+        Some(Coordinate { x, y })
+    }
+
+    pub fn manhattan_distance(self, other: Coordinate) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(usize, usize)> for Coordinate {
+    fn from(value: (usize, usize)) -> Self {
+        Self {
+            x: value.0,
+            y: value.1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_offset() {
+        let coordinate = Coordinate::new(1, 1);
+
+        assert_eq!(
+            coordinate.checked_offset(1, -1),
+            Some(Coordinate::new(2, 0))
+        );
+        assert_eq!(
+            coordinate.checked_offset(-1, 0),
+            Some(Coordinate::new(0, 1))
+        );
+        assert_eq!(coordinate.checked_offset(-2, 0), None);
+        assert_eq!(coordinate.checked_offset(0, -2), None);
+    }
+
+    #[test]
+    fn test_checked_offset_overflow() {
+        let coordinate = Coordinate::new(usize::MAX, 0);
+
+        assert_eq!(coordinate.checked_offset(1, 0), None);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let start = Coordinate::new(4, 0);
+        let end = Coordinate::new(1, 6);
+
+        assert_eq!(start.manhattan_distance(end), 9);
+        assert_eq!(end.manhattan_distance(start), 9);
+        assert_eq!(start.manhattan_distance(start), 0);
+    }
+
+    #[test]
+    fn test_from_tuple() {
+        let coordinate: Coordinate = (3, 7).into();
+
+        assert_eq!(coordinate, Coordinate { x: 3, y: 7 });
+    }
+}
@@ -0,0 +1,516 @@
+use std::{
+    fmt::Display,
+    iter::StepBy,
+    ops::{Index, IndexMut},
+    slice::{ChunksExact, ChunksExactMut, Iter},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::coordinate::{Coordinate, ORTHOGONAL_OFFSETS, SURROUNDING_OFFSETS};
+
+// Cells are stored row by row, so the cell at (x, y) lives at y * width + x
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        let len = cell_count(width, height)?;
+        if cells.len() != len {
+            bail!(
+                "Expected {} cells for a {}x{} grid, found {}",
+                len,
+                width,
+                height,
+                cells.len()
+            );
+        }
+
+        Ok(Self::with_cells(width, height, cells))
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Result<Self>
+    where
+        T: Clone,
+    {
+        let len = cell_count(width, height)?;
+        Ok(Self::with_cells(width, height, vec![value; len]))
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                bail!("Row {} has width {}, expected {}", y, row.len(), width);
+            }
+            cells.extend(row);
+        }
+
+        Ok(Self::with_cells(width, height, cells))
+    }
+
+    // Parses one row per line, mapping every character to a cell
+    pub fn parse_with<F>(input: &str, mut f: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).with_context(|| format!("Invalid cell at x: {}, y: {}", x, y))?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    bail!("Row {} has width {}, expected {}", y, row_width, width)
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self::with_cells(width.unwrap_or(0), height, cells))
+    }
+
+    // A grid with no cells is 0x0 whichever side was empty, so rows of nothing aren't counted
+    // in the height
+    fn with_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        if cells.is_empty() {
+            return Self {
+                width: 0,
+                height: 0,
+                cells,
+            };
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        coordinate.x < self.width && coordinate.y < self.height
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        if self.contains(coordinate) {
+            Some(&self.cells[self.index_of(coordinate)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        if self.contains(coordinate) {
+            let index = self.index_of(coordinate);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    // Moves the coordinate by the given offset, returning None if it would leave the grid
+    pub fn offset(&self, coordinate: Coordinate, dx: isize, dy: isize) -> Option<Coordinate> {
+        coordinate
+            .checked_offset(dx, dy)
+            .filter(|c| self.contains(*c))
+    }
+
+    // The orthogonal neighbours inside the grid, clockwise from north
+    pub fn neighbours4(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        ORTHOGONAL_OFFSETS
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(coordinate, dx, dy))
+    }
+
+    // The orthogonal and diagonal neighbours inside the grid, clockwise from north
+    pub fn neighbours8(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        SURROUNDING_OFFSETS
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(coordinate, dx, dy))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        // chunks_exact panics on a zero chunk size, which an empty grid would have
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(
+            x < self.width,
+            "Column {} is outside a grid of width {}",
+            x,
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = StepBy<Iter<'_, T>>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // Iterates over every cell in row-major order along with its coordinate
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.coordinate_of(index), cell))
+    }
+
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coordinate { x, y }))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Swaps rows and columns, reflecting the grid along its main diagonal
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| Coordinate { x: y, y: x })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rebuild(self.height, self.width, move |x, y| Coordinate {
+            x: y,
+            y: height - 1 - x,
+        })
+    }
+
+    pub fn rotate_anticlockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rebuild(self.height, self.width, move |x, y| Coordinate {
+            x: width - 1 - y,
+            y: x,
+        })
+    }
+
+    // Builds a new grid where each cell is copied from the source coordinate given by `source`
+    fn rebuild<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        T: Clone,
+        F: Fn(usize, usize) -> Coordinate,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn index_of(&self, coordinate: Coordinate) -> usize {
+        coordinate.y * self.width + coordinate.x
+    }
+
+    fn coordinate_of(&self, index: usize) -> Coordinate {
+        Coordinate {
+            x: index % self.width,
+            y: index / self.width,
+        }
+    }
+}
+
+fn cell_count(width: usize, height: usize) -> Result<usize> {
+    width
+        .checked_mul(height)
+        .with_context(|| format!("A {}x{} grid has too many cells", width, height))
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Coordinate) -> &Self::Output {
+        self.get(index).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside a {}x{} grid",
+                index, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, index: Coordinate) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", index, width, height))
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    <T as TryFrom<char>>::Error: Display,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| T::try_from(c).map_err(|e| anyhow!("{}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn numbered() -> Grid<u32> {
+        Grid::parse_with(
+            indoc! {"
+                123
+                456
+            "},
+            |c| c.to_digit(10).context("Not a digit"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let grid = numbered();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Coordinate::new(0, 0)], 1);
+        assert_eq!(grid[Coordinate::new(2, 1)], 6);
+    }
+
+    #[test]
+    fn test_parse_with_reports_position() {
+        let error =
+            Grid::parse_with("12\n3x", |c| c.to_digit(10).context("Not a digit")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Invalid cell at x: 1, y: 1");
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        let actual = Grid::parse_with("123\n45", Ok);
+
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let grid: Grid<char> = "ab\r\ncd\r\n".parse().unwrap();
+
+        assert_eq!(
+            grid,
+            Grid::from_rows(vec![vec!['a', 'b'], vec!['c', 'd']]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_new_checks_length() {
+        assert!(Grid::new(2, 2, vec![0; 4]).is_ok());
+        assert!(Grid::new(2, 2, vec![0; 3]).is_err());
+        assert!(Grid::new(usize::MAX, 2, vec![0; 2]).is_err());
+        assert!(Grid::<u8>::new(usize::MAX, usize::MAX, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_filled() {
+        let grid = Grid::filled(3, 2, 'x').unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid.iter().all(|(_, c)| *c == 'x'));
+        assert!(Grid::filled(usize::MAX, 2, 'x').is_err());
+    }
+
+    #[test]
+    fn test_empty_rows_make_an_empty_grid() {
+        let parsed: Grid<char> = "\n\n\n".parse().unwrap();
+        let from_rows = Grid::<char>::from_rows(vec![vec![], vec![]]).unwrap();
+        let new = Grid::<char>::new(0, 5, vec![]).unwrap();
+
+        for grid in [parsed, from_rows, new] {
+            assert_eq!((grid.width(), grid.height()), (0, 0));
+            assert!(grid.is_empty());
+            assert_eq!(grid.rows().count(), 0);
+            assert_eq!(grid.coordinates().count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = numbered();
+
+        assert_eq!(grid.get(Coordinate::new(3, 0)), None);
+        assert_eq!(grid.get(Coordinate::new(0, 2)), None);
+    }
+
+    #[test]
+    fn test_offset() {
+        let grid = numbered();
+        let corner = Coordinate::new(2, 1);
+
+        assert_eq!(grid.offset(corner, -2, -1), Some(Coordinate::new(0, 0)));
+        assert_eq!(grid.offset(corner, 1, 0), None);
+        assert_eq!(grid.offset(corner, 0, -2), None);
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let grid = numbered();
+
+        let actual = grid.neighbours4(Coordinate::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(actual, [Coordinate::new(1, 0), Coordinate::new(0, 1)]);
+
+        let actual = grid.neighbours4(Coordinate::new(1, 0)).count();
+        assert_eq!(actual, 3);
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let grid = numbered();
+
+        let actual = grid
+            .neighbours8(Coordinate::new(0, 1))
+            .map(|c| grid[c])
+            .collect::<Vec<_>>();
+        assert_eq!(actual, [1, 2, 5]);
+
+        let actual = grid.neighbours8(Coordinate::new(1, 0)).count();
+        assert_eq!(actual, 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = numbered();
+
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn test_rows_mut() {
+        let mut grid = numbered();
+
+        grid.rows_mut().for_each(|row| row.reverse());
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[3, 2, 1], [6, 5, 4]]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = numbered();
+
+        let actual = grid.iter().find(|(_, cell)| **cell == 5).map(|(c, _)| c);
+        assert_eq!(actual, Some(Coordinate::new(1, 1)));
+        assert_eq!(grid.coordinates().count(), 6);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let actual = numbered().transpose();
+
+        let expected = Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_rotate_clockwise() {
+        let actual = numbered().rotate_clockwise();
+
+        let expected = Grid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_rotate_anticlockwise() {
+        let actual = numbered().rotate_anticlockwise();
+
+        let expected = Grid::from_rows(vec![vec![3, 6], vec![2, 5], vec![1, 4]]).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_full_rotation() {
+        let grid = numbered();
+
+        let clockwise = (0..4).fold(grid.clone(), |g, _| g.rotate_clockwise());
+        let anticlockwise = grid.rotate_clockwise().rotate_anticlockwise();
+
+        assert_eq!(clockwise, grid);
+        assert_eq!(anticlockwise, grid);
+    }
+
+    #[test]
+    fn test_map() {
+        let actual = numbered().map(|n| n % 2 == 0);
+
+        assert_eq!(actual.row(0), [false, true, false]);
+    }
+}
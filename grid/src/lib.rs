@@ -0,0 +1,5 @@
+mod coordinate;
+mod grid;
+
+pub use coordinate::Coordinate;
+pub use grid::Grid;